@@ -0,0 +1,70 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use json_test::JsonTest;
+use jsonpath_rust::JsonPath;
+use serde_json::{json, Value};
+use std::str::FromStr;
+
+/// A document with `count` users, every other one active.
+fn users(count: usize) -> Value {
+    let users: Vec<Value> = (0..count)
+        .map(|id| json!({
+            "id": id,
+            "name": format!("user{}", id),
+            "active": id % 2 == 0,
+            "address": {"city": "Paris", "zip": "75001"}
+        }))
+        .collect();
+    json!({"users": users})
+}
+
+/// Asserting on the same path repeatedly: a fresh test evaluates the path
+/// every time, while one test evaluates it once and serves the cache.
+fn repeated_path(c: &mut Criterion) {
+    let data = users(1_000);
+    let path = "$.users[?(@.active == true)].name";
+    let mut group = c.benchmark_group("repeated_path");
+
+    group.bench_function("uncached", |b| {
+        b.iter(|| {
+            for _ in 0..20 {
+                let test = JsonTest::new(&data);
+                black_box(test.assert_path(black_box(path)).iter_matches().count());
+            }
+        })
+    });
+    group.bench_function("cached", |b| {
+        b.iter(|| {
+            let test = JsonTest::new(&data);
+            for _ in 0..20 {
+                black_box(test.assert_path(black_box(path)).iter_matches().count());
+            }
+        })
+    });
+    group.finish();
+}
+
+/// Resolving a filter that matches thousands of objects. `JsonPath::find`
+/// copies every match into a new array, which gives a reference point for
+/// assertions that borrow the matched nodes from the document instead.
+fn large_filter(c: &mut Criterion) {
+    let data = users(10_000);
+    let path = "$.users[?(@.active == true)]";
+    let mut group = c.benchmark_group("large_filter");
+
+    group.bench_function("jsonpath find (copies matches)", |b| {
+        b.iter(|| {
+            let parsed = JsonPath::<Value>::from_str(black_box(path)).unwrap();
+            black_box(parsed.find(&data))
+        })
+    });
+    group.bench_function("assert_path (borrows matches)", |b| {
+        b.iter(|| {
+            let test = JsonTest::new(&data);
+            black_box(test.assert_path(black_box(path)).iter_matches().count())
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, repeated_path, large_filter);
+criterion_main!(benches);
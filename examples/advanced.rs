@@ -39,7 +39,7 @@ fn main() {
         }
     });
 
-    let mut test = JsonTest::new(&data);
+    let test = JsonTest::new(&data);
 
     test.assert_path("$.orders[?(@.status == 'shipped')].customer")
         // Find customer with shipped order using JSONPath filter
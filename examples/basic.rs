@@ -27,7 +27,7 @@ fn main() {
         }
     });
 
-    let mut test = JsonTest::new(&data);
+    let test = JsonTest::new(&data);
 
     test.assert_path("$.users[0].name")
         // Verify first user's name exists and is a string
@@ -29,7 +29,7 @@ fn main() {
         }
     });
 
-    let mut test = JsonTest::new(&data);
+    let test = JsonTest::new(&data);
 
     test.assert_path("$.config.db_settings")
         // Verify all required database properties exist
@@ -57,10 +57,10 @@ fn main() {
 
         // Check feature flags
         .assert_path("$.config.feature_flags")
-        // Count disabled features
+        // Every flag is a boolean
         .properties_matching(|_| true)
         .count(3)
-        .all(|(_, value)| value.is_boolean())
+        .all_values_of_type("boolean")
         .and()
         // Verify specific flags
         .has_property_value("debug_mode", json!(false))
@@ -1,7 +1,24 @@
+use crate::assertions::captures::CaptureAssertion;
+use crate::assertions::each::EachAssertion;
+use crate::assertions::negated::NegatedAssertion;
+use crate::assertions::numeric_stats::NumericStats;
+use crate::color;
+use crate::error::{type_name, JsonPathError};
+use crate::matchers::{JsonMatcher, RegexFlags};
 use crate::JsonTest;
-use jsonpath_rust::JsonPath;
-use serde_json::{Map, Value};
+use jsonpath_rust::{JsonPath, JsonPathValue};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Number, Value};
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::sync::OnceLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 /// Provides assertions for JSON values accessed via JSONPath expressions.
 ///
@@ -9,6 +26,18 @@ use std::str::FromStr;
 /// for testing JSON values. All assertion methods follow a builder pattern,
 /// returning `&mut Self` for chaining.
 ///
+/// # Multiple matches
+///
+/// A path can select more than one value. Wildcards (`$.users[*]`, `$.user.*`),
+/// unions (`$.user['name','email']`, `$.items[0,2]`), slices (`$.items[1:3]`),
+/// filters (`$.items[?(@.price > 10)]`) and recursive descent (`$..name`) all
+/// keep every match, in document order. Single-value assertions such as `equals`
+/// or `is_string` inspect the first match only; use `values_equal` to assert
+/// on the whole selection.
+///
+/// Matched nodes are borrowed from the document rather than copied, so selecting
+/// large subtrees or many filter results does not clone them.
+///
 /// # Examples
 ///
 /// ```rust
@@ -22,7 +51,7 @@ use std::str::FromStr;
 ///     }
 /// });
 ///
-/// let mut test = JsonTest::new(&data);
+/// let test = JsonTest::new(&data);
 /// test.assert_path("$.user")
 ///     .exists()
 ///     .has_property("name")
@@ -31,56 +60,118 @@ use std::str::FromStr;
 #[derive(Debug)]
 pub struct JsonPathAssertion<'a> {
     pub(crate) path_str: String,
-    pub(crate) current_values: Vec<Value>,
-    pub(crate) test: Option<&'a mut JsonTest<'a>>,
+    pub(crate) current_values: Vec<Cow<'a, Value>>,
+    pub(crate) test: Option<&'a JsonTest<'a>>,
 }
 
 impl<'a> JsonPathAssertion<'a> {
-    pub(crate) fn new_with_test(test: &'a mut JsonTest<'a>, json: &'a Value, path: &str) -> Self {
-        let parsed_path = JsonPath::<Value>::from_str(path)
-            .unwrap_or_else(|e| panic!("Invalid JSONPath expression: {}", e));
-
-        let result = parsed_path.find(json);
-        let current_values = match result {
-            Value::Array(values) => {
-                if !path.contains('[') && values.len() == 1 {
-                    vec![values[0].clone()]
-                } else {
-                    values
-                }
-            }
-            Value::Null => vec![],
-            other => vec![other],
-        };
-
+    pub(crate) fn new_with_test(test: &'a JsonTest<'a>, path: &str) -> Self {
         Self {
             path_str: path.to_string(),
-            current_values,
+            current_values: test.resolve(path),
             test: Some(test),
         }
     }
 
-    #[cfg(test)]
-    pub fn new_for_test(json: &'a Value, path: &str) -> Self {
-        let parsed_path = JsonPath::<Value>::from_str(path)
-            .unwrap_or_else(|e| panic!("Invalid JSONPath expression: {}", e));
-
-        let result = parsed_path.find(json);
-        let current_values = match result {
-            Value::Array(values) => {
-                if !path.contains('[') && values.len() == 1 {
-                    vec![values[0].clone()]
-                } else {
-                    values
-                }
-            }
-            Value::Null => vec![],
-            other => vec![other],
+    /// Creates an assertion over values that were resolved without a JSONPath expression.
+    pub(crate) fn with_values(test: &'a JsonTest<'a>, path_str: String, values: Vec<Cow<'a, Value>>) -> Self {
+        Self {
+            path_str,
+            current_values: values,
+            test: Some(test),
+        }
+    }
+
+    /// Creates an assertion over a child of the current value.
+    ///
+    /// The child is borrowed from the document when the current value is, and
+    /// the new assertion has no value if `select` finds nothing.
+    pub(crate) fn child<F>(&self, path_str: String, select: F) -> JsonPathAssertion<'a>
+    where
+        F: for<'v> Fn(&'v Value) -> Option<&'v Value>,
+    {
+        let current_values = match self.current_values.first() {
+            Some(Cow::Borrowed(parent)) => select(parent).map(Cow::Borrowed).into_iter().collect(),
+            Some(Cow::Owned(parent)) => select(parent).cloned().map(Cow::Owned).into_iter().collect(),
+            None => Vec::new(),
+        };
+        JsonPathAssertion {
+            path_str,
+            current_values,
+            test: self.test,
+        }
+    }
+
+    /// Returns the first matched value, which single-value assertions inspect.
+    pub(crate) fn first_value(&self) -> Option<&Value> {
+        self.current_values.first().map(Cow::as_ref)
+    }
+
+    /// Panics because the path matched nothing, adding a filter hint when
+    /// the test has strict filters enabled.
+    fn no_value(&self) -> ! {
+        self.panic_with(self.missing_error(None))
+    }
+
+    /// Builds the error for a path that matched nothing.
+    pub(crate) fn missing_error(&self, expected: Option<Value>) -> JsonPathError {
+        let message = match self.filter_hint() {
+            Some(hint) => format!("No value found at {}\n{}", self.path_str, hint),
+            None => format!("No value found at {}", self.path_str),
+        };
+        JsonPathError::assertion_failed(message, self.path_str.as_str(), Value::Null, expected, HashMap::new())
+    }
+
+    /// Builds the error for a failed check on this path.
+    fn failure(&self, message: String) -> JsonPathError {
+        JsonPathError::assertion_failed(message, self.path_str.as_str(), Value::Null, None, HashMap::new())
+    }
+
+    /// Reports a failed check on this path; see `report_error`.
+    pub(crate) fn report(&self, message: String) {
+        self.report_error(self.failure(message));
+    }
+
+    /// Reports a failed check.
+    ///
+    /// Inside `JsonTest::assert_all` the failure is recorded and the caller
+    /// returns normally; otherwise this panics with the failure message,
+    /// highlighted when the test has colors enabled.
+    pub(crate) fn report_error(&self, error: JsonPathError) {
+        let error = match self.test {
+            Some(test) => match test.collect_failure(error) {
+                Some(error) => error,
+                None => return,
+            },
+            None => error,
         };
+        self.panic_with(error)
+    }
+
+    /// Panics with the failure message, highlighted when the test has colors
+    /// enabled.
+    ///
+    /// Methods that return a value rather than `&mut Self` panic through this
+    /// directly, since they have nothing to return inside `assert_all`.
+    pub(crate) fn panic_with(&self, error: JsonPathError) -> ! {
+        let message = failure_message(error);
+        if self.test.is_some_and(JsonTest::colors_enabled) {
+            panic!("{}", color::highlight(&message, &self.path_str));
+        }
+        panic!("{}", message)
+    }
+
+    fn filter_hint(&self) -> Option<String> {
+        self.test
+            .filter(|test| test.strict_filters)
+            .and_then(|test| filter_type_hint(test.json(), &self.path_str))
+    }
 
+    #[cfg(test)]
+    pub fn new_for_test(json: &'a Value, path: &str) -> Self {
         Self {
             path_str: path.to_string(),
-            current_values,
+            current_values: resolve_path(json, path),
             test: None,
         }
     }
@@ -93,17 +184,34 @@ impl<'a> JsonPathAssertion<'a> {
     /// # use json_test::JsonTest;
     /// # use serde_json::json;
     /// # let data = json!({"user": {"name": "John"}});
-    /// # let mut test = JsonTest::new(&data);
+    /// # let test = JsonTest::new(&data);
     /// test.assert_path("$.user.name")
     ///     .exists();
     /// ```
     ///
     /// # Panics
     ///
-    /// Panics if the path does not exist in the JSON structure.
-    pub fn exists(&'a mut self) -> &'a mut Self {
+    /// Panics if the path does not exist in the JSON structure. For simple paths
+    /// the message names the deepest prefix that does exist, which makes typos
+    /// such as `$.user.setings.theme` easy to spot.
+    pub fn exists(&mut self) -> &mut Self {
         if self.current_values.is_empty() {
-            panic!("Path {} does not exist", self.path_str);
+            let prefix = self
+                .test
+                .and_then(|test| deepest_existing_prefix(test.json(), &self.path_str));
+            match prefix {
+                Some((prefix, node)) => fail!(
+                    self,
+                    "Path {} does not exist; deepest existing prefix is {} ({})",
+                    self.path_str,
+                    prefix,
+                    describe_node(node)
+                ),
+                None => match self.filter_hint() {
+                    Some(hint) => fail!(self, "Path {} does not exist\n{}", self.path_str, hint),
+                    None => fail!(self, "Path {} does not exist", self.path_str),
+                },
+            }
         }
         self
     }
@@ -116,7 +224,7 @@ impl<'a> JsonPathAssertion<'a> {
     /// # use json_test::JsonTest;
     /// # use serde_json::json;
     /// # let data = json!({"user": {"name": "John"}});
-    /// # let mut test = JsonTest::new(&data);
+    /// # let test = JsonTest::new(&data);
     /// test.assert_path("$.user.email")
     ///     .does_not_exist();
     /// ```
@@ -124,488 +232,6552 @@ impl<'a> JsonPathAssertion<'a> {
     /// # Panics
     ///
     /// Panics if the path exists in the JSON structure.
-    pub fn does_not_exist(&'a mut self) -> &'a mut Self {
+    pub fn does_not_exist(&mut self) -> &mut Self {
         if !self.current_values.is_empty() {
-            panic!("Path {} exists but should not. Found values: {:?}",
+            fail!(self, "Path {} exists but should not. Found values: {:?}",
                    self.path_str, self.current_values);
         }
         self
     }
 
-    /// Asserts that the value at the current path equals the expected value.
+    /// Asserts that the path matched exactly `n` values.
+    ///
+    /// Filters, wildcards and unions can match any number of values, while most
+    /// assertions only inspect the first one. Checking the count first guards
+    /// against silently testing one match out of many.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use json_test::JsonTest;
     /// # use serde_json::json;
-    /// # let data = json!({"user": {"name": "John"}});
-    /// # let mut test = JsonTest::new(&data);
-    /// test.assert_path("$.user.name")
+    /// # let data = json!({"users": [
+    /// #     {"name": "John", "role": "admin"},
+    /// #     {"name": "Jane", "role": "user"}
+    /// # ]});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.users[?(@.role == 'admin')].name")
+    ///     .having_match_count(1)
     ///     .equals(json!("John"));
     /// ```
     ///
     /// # Panics
     ///
-    /// - Panics if no value exists at the path
-    /// - Panics if the value doesn't match the expected value
-    pub fn equals(&'a mut self, expected: Value) -> &'a mut Self {
-        match self.current_values.get(0) {
-            Some(actual) if actual == &expected => self,
-            Some(actual) => panic!(
-                "Value mismatch at {}\nExpected: {}\nActual: {}",
-                self.path_str, expected, actual
-            ),
-            None => panic!("No value found at {}", self.path_str),
+    /// Panics if the number of matched values differs from `n`.
+    pub fn having_match_count(&mut self, n: usize) -> &mut Self {
+        if self.current_values.len() != n {
+            fail!(
+                self,
+                "Path {} matched {} values, expected {}\nMatches: {:?}",
+                self.path_str,
+                self.current_values.len(),
+                n,
+                self.current_values
+            );
         }
+        self
     }
 
-    /// Asserts that the value at the current path is a string.
+    /// Asserts that the path matched exactly `expected` values.
+    ///
+    /// This is an alias for [`having_match_count`](Self::having_match_count).
+    /// It counts the nodes selected by the path, not the elements of a value:
+    /// `$.users` selects one node, the array itself, so its count is 1 however
+    /// long the array is, while `$.users[*]` selects one node per element. Use
+    /// `has_length` to check the length of a single array.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use json_test::JsonTest;
     /// # use serde_json::json;
-    /// # let data = json!({"message": "Hello"});
-    /// # let mut test = JsonTest::new(&data);
-    /// test.assert_path("$.message")
-    ///     .is_string();
+    /// # let data = json!({"users": [{"active": true}, {"active": false}, {"active": true}]});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.users").match_count(1).has_length(3);
+    /// test.assert_path("$.users[*]").match_count(3);
+    /// test.assert_path("$.users[?(@.active == true)]").match_count(2);
     /// ```
     ///
     /// # Panics
     ///
-    /// - Panics if no value exists at the path
-    /// - Panics if the value is not a string
-    pub fn is_string(&'a mut self) -> &'a mut Self {
-        match self.current_values.get(0) {
-            Some(Value::String(_)) => self,
-            Some(v) => panic!("Expected string at {}, got {:?}", self.path_str, v),
-            None => panic!("No value found at {}", self.path_str),
-        }
+    /// Panics if the number of matched values differs from `expected`.
+    pub fn match_count(&mut self, expected: usize) -> &mut Self {
+        self.having_match_count(expected)
     }
 
-    /// Asserts that the string value contains the given substring.
+    /// Asserts that the value at the current path equals the expected value.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use json_test::JsonTest;
     /// # use serde_json::json;
-    /// # let data = json!({"email": "test@example.com"});
-    /// # let mut test = JsonTest::new(&data);
-    /// test.assert_path("$.email")
-    ///     .contains_string("@example");
+    /// # let data = json!({"user": {"name": "John"}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.user.name")
+    ///     .equals(json!("John"));
     /// ```
     ///
     /// # Panics
     ///
     /// - Panics if no value exists at the path
-    /// - Panics if the value is not a string
-    /// - Panics if the string does not contain the substring
-    pub fn contains_string(&'a mut self, substring: &str) -> &'a mut Self {
-        match self.current_values.get(0) {
-            Some(Value::String(s)) if s.contains(substring) => self,
-            Some(Value::String(s)) => panic!(
-                "String at {} does not contain '{}'\nActual: {}",
-                self.path_str, substring, s
-            ),
-            Some(v) => panic!("Expected string at {}, got {:?}", self.path_str, v),
-            None => panic!("No value found at {}", self.path_str),
+    /// - Panics if the value doesn't match the expected value
+    pub fn equals(&mut self, expected: Value) -> &mut Self {
+        if let Err(error) = self.check_equals(&expected) {
+            fail_with!(self, error);
         }
+        self
     }
 
-    /// Asserts that the string value starts with the given prefix.
+    /// Asserts that the value equals `expected` once it is serialized to JSON.
+    ///
+    /// This lets tests reuse their own `Serialize` types as expected values
+    /// instead of `json!` literals that can drift from the real shape. The first
+    /// differing field is named in the failure message.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use json_test::JsonTest;
+    /// # use serde::Serialize;
     /// # use serde_json::json;
-    /// # let data = json!({"id": "user_123"});
-    /// # let mut test = JsonTest::new(&data);
-    /// test.assert_path("$.id")
-    ///     .starts_with("user_");
+    /// #[derive(Serialize)]
+    /// struct User {
+    ///     name: String,
+    ///     age: u32,
+    /// }
+    ///
+    /// let data = json!({"user": {"name": "John", "age": 30}});
+    /// let test = JsonTest::new(&data);
+    /// test.assert_path("$.user")
+    ///     .equals_serialized(&User { name: "John".to_string(), age: 30 });
     /// ```
     ///
     /// # Panics
     ///
+    /// - Panics if `expected` cannot be serialized to JSON
     /// - Panics if no value exists at the path
-    /// - Panics if the value is not a string
-    /// - Panics if the string does not start with the prefix
-    pub fn starts_with(&'a mut self, prefix: &str) -> &'a mut Self {
-        match self.current_values.get(0) {
-            Some(Value::String(s)) if s.starts_with(prefix) => self,
-            Some(Value::String(s)) => panic!(
-                "String at {} does not start with '{}'\nActual: {}",
-                self.path_str, prefix, s
-            ),
-            Some(v) => panic!("Expected string at {}, got {:?}", self.path_str, v),
-            None => panic!("No value found at {}", self.path_str),
+    /// - Panics if the value doesn't equal the serialized value
+    pub fn equals_serialized<T: Serialize>(&mut self, expected: &T) -> &mut Self {
+        let expected = serde_json::to_value(expected).unwrap_or_else(|e| {
+            panic!("Failed to serialize expected value for {}: {}", self.path_str, e)
+        });
+
+        match self.first_value() {
+            Some(actual) if actual == &expected => self,
+            Some(actual) => {
+                let subpath = first_approx_difference(actual, &expected, 0.0, "")
+                    .map_or(String::new(), |(subpath, _, _)| subpath);
+                fail!(
+                    self,
+                    "Value mismatch at {} (first difference at {}{})\nExpected: {}\nActual: {}",
+                    self.path_str, self.path_str, subpath, expected, actual
+                );
+            }
+            None => fail_with!(self, self.missing_error(None)),
         }
     }
 
-    /// Asserts that the string value ends with the given suffix.
+    /// Asserts that the value equals the expected value, allowing numbers to differ by `epsilon`.
+    ///
+    /// Objects and arrays are compared recursively. Numbers anywhere in the
+    /// structure are equal when their difference is at most `epsilon`; all other
+    /// values must match exactly. The first difference found is reported.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use json_test::JsonTest;
     /// # use serde_json::json;
-    /// # let data = json!({"file": "document.pdf"});
-    /// # let mut test = JsonTest::new(&data);
-    /// test.assert_path("$.file")
-    ///     .ends_with(".pdf");
+    /// # let data = json!({"summary": {"mean": 0.30000000000000004, "points": [0.1, 0.2], "unit": "s"}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.summary")
+    ///     .equals_approx(json!({"mean": 0.3, "points": [0.1, 0.2], "unit": "s"}), 1e-9);
     /// ```
     ///
     /// # Panics
     ///
     /// - Panics if no value exists at the path
-    /// - Panics if the value is not a string
-    /// - Panics if the string does not end with the suffix
-    pub fn ends_with(&'a mut self, suffix: &str) -> &'a mut Self {
-        match self.current_values.get(0) {
-            Some(Value::String(s)) if s.ends_with(suffix) => self,
-            Some(Value::String(s)) => panic!(
-                "String at {} does not end with '{}'\nActual: {}",
-                self.path_str, suffix, s
-            ),
-            Some(v) => panic!("Expected string at {}, got {:?}", self.path_str, v),
-            None => panic!("No value found at {}", self.path_str),
+    /// - Panics if the value differs from `expected` beyond the tolerance
+    pub fn equals_approx(&mut self, expected: Value, epsilon: f64) -> &mut Self {
+        let actual = match self.first_value() {
+            Some(actual) => actual,
+            None => fail_with!(self, self.missing_error(None)),
+        };
+
+        if let Some((subpath, expected, actual)) = first_approx_difference(actual, &expected, epsilon, "") {
+            let describe = |v: Option<&Value>| v.map_or("(missing)".to_string(), |v| v.to_string());
+            fail!(
+                self,
+                "Value mismatch at {}{} (epsilon {})\nExpected: {}\nActual: {}",
+                self.path_str, subpath, epsilon, describe(expected), describe(actual)
+            );
         }
+        self
     }
 
-    /// Asserts that the string value matches the given regular expression pattern.
+    /// Asserts that the object equals `expected` and that its keys iterate in the given order.
+    ///
+    /// Object equality never depends on key order, so the two properties are
+    /// checked separately and reported with distinct messages. Key order is only
+    /// meaningful when serde_json's `preserve_order` feature is enabled; without
+    /// it, keys always iterate in sorted order.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use json_test::JsonTest;
     /// # use serde_json::json;
-    /// # let data = json!({"email": "test@example.com"});
-    /// # let mut test = JsonTest::new(&data);
-    /// test.assert_path("$.email")
-    ///     .matches_pattern(r"^[^@]+@[^@]+\.[^@]+$");
+    /// # let data = json!({"point": {"x": 1, "y": 2}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.point")
+    ///     .equals_with_key_order(json!({"y": 2, "x": 1}), &["x", "y"]);
     /// ```
     ///
     /// # Panics
     ///
     /// - Panics if no value exists at the path
-    /// - Panics if the value is not a string
-    /// - Panics if the pattern is invalid
-    /// - Panics if the string does not match the pattern
-
-    pub fn matches_pattern(&'a mut self, pattern: &str) -> &'a mut Self {
-        let regex = regex::Regex::new(pattern)
-            .unwrap_or_else(|e| panic!("Invalid regex pattern: {}", e));
-
-        match self.current_values.get(0) {
-            Some(Value::String(s)) if regex.is_match(s) => self,
-            Some(Value::String(s)) => panic!(
-                "String at {} does not match pattern '{}'\nActual: {}",
-                self.path_str, pattern, s
+    /// - Panics if the value doesn't equal `expected`
+    /// - Panics if the value is not an object or its keys are not exactly
+    ///   `expected_keys_in_order`, in that order
+    pub fn equals_with_key_order(&mut self, expected: Value, expected_keys_in_order: &[&str]) -> &mut Self {
+        let obj = match self.first_value() {
+            Some(actual) if actual != &expected => fail!(
+                self,
+                "Value mismatch at {}\nExpected: {}\nActual: {}",
+                self.path_str, expected, actual
             ),
-            Some(v) => panic!("Expected string at {}, got {:?}", self.path_str, v),
-            None => panic!("No value found at {}", self.path_str),
+            Some(Value::Object(obj)) => obj,
+            Some(v) => fail!(self, "Expected object at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        };
+
+        let actual_keys: Vec<&str> = obj.keys().map(|k| k.as_str()).collect();
+        if actual_keys != expected_keys_in_order {
+            fail!(
+                self,
+                "Keys at {} are not in the expected order\nExpected: {:?}\nActual: {:?}",
+                self.path_str, expected_keys_in_order, actual_keys
+            );
         }
+        self
     }
 
-    /// Asserts that the value at the current path is a number.
+    /// Asserts that the value deeply equals the expected value, except at the ignored paths.
+    ///
+    /// Each entry of `ignore_paths` is a JSONPath evaluated relative to the current
+    /// value, so `$` refers to the value itself. Matching nodes are removed from both
+    /// sides before comparing, which suits fixtures with volatile fields such as
+    /// timestamps or generated IDs. The first remaining difference is reported.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use json_test::JsonTest;
     /// # use serde_json::json;
-    /// # let data = json!({"count": 42});
-    /// # let mut test = JsonTest::new(&data);
-    /// test.assert_path("$.count")
-    ///     .is_number();
+    /// # let data = json!({"order": {"id": "ord_8f2k", "status": "paid", "items": [{"sku": "A1", "added_at": 1718000000}]}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.order")
+    ///     .equals_ignoring(
+    ///         json!({"id": "ord_x", "status": "paid", "items": [{"sku": "A1", "added_at": 0}]}),
+    ///         &["$.id", "$.items[*].added_at"],
+    ///     );
     /// ```
     ///
     /// # Panics
     ///
     /// - Panics if no value exists at the path
-    /// - Panics if the value is not a number
-    pub fn is_number(&'a mut self) -> &'a mut Self {
-        match self.current_values.get(0) {
-            Some(Value::Number(_)) => self,
-            Some(v) => panic!("Expected number at {}, got {:?}", self.path_str, v),
-            None => panic!("No value found at {}", self.path_str),
+    /// - Panics if any ignore path is an invalid JSONPath expression
+    /// - Panics if the values differ outside the ignored paths
+    pub fn equals_ignoring(&mut self, expected: Value, ignore_paths: &[&str]) -> &mut Self {
+        let mut actual = match self.first_value() {
+            Some(actual) => actual.clone(),
+            None => fail_with!(self, self.missing_error(None)),
+        };
+        let mut expected = expected;
+
+        for path in ignore_paths {
+            for doc in [&mut actual, &mut expected] {
+                let nodes: Vec<String> = find_matches(doc, path)
+                    .into_iter()
+                    .filter_map(|found| found.normalized)
+                    .collect();
+                for normalized in nodes.iter().rev() {
+                    remove_node(doc, normalized);
+                }
+            }
         }
+
+        if actual != expected {
+            // Numbers that only compare equal as floats, such as `1` and `1.0`,
+            // have no approximate difference, so the whole values are reported.
+            let (subpath, expected, actual) = first_approx_difference(&actual, &expected, 0.0, "")
+                .unwrap_or_else(|| (String::new(), Some(&expected), Some(&actual)));
+            let describe = |v: Option<&Value>| v.map_or("(missing)".to_string(), |v| v.to_string());
+            fail!(
+                self,
+                "Value mismatch at {}{} (ignoring {})\nExpected: {}\nActual: {}",
+                self.path_str,
+                subpath,
+                ignore_paths.join(", "),
+                describe(expected),
+                describe(actual)
+            );
+        }
+        self
     }
 
-    /// Asserts that the numeric value is greater than the given value.
+    /// Asserts that the value equals `expected`, listing every difference on failure.
+    ///
+    /// Both values are walked recursively, and each differing path is reported
+    /// on its own line, for example `$.user.age: expected 25, actual 30` or
+    /// `$.user.email: missing in actual, expected "john@example.com"`. Changes of
+    /// type name both types. This is easier to read than `equals` for large
+    /// nested values.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use json_test::JsonTest;
     /// # use serde_json::json;
-    /// # let data = json!({"age": 21});
-    /// # let mut test = JsonTest::new(&data);
-    /// test.assert_path("$.age")
-    ///     .is_greater_than(18);
+    /// # let data = json!({"user": {"name": "John", "roles": ["admin"]}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.user")
+    ///     .equals_with_diff(json!({"name": "John", "roles": ["admin"]}));
     /// ```
     ///
     /// # Panics
     ///
     /// - Panics if no value exists at the path
-    /// - Panics if the value is not a number
-    /// - Panics if the value is not greater than the given value
-    pub fn is_greater_than(&'a mut self, value: i64) -> &'a mut Self {
-        match self.current_values.get(0) {
-            Some(Value::Number(n)) if n.as_i64().map_or(false, |x| x > value) => self,
-            Some(Value::Number(n)) => panic!(
-                "Number at {} is not greater than {}\nActual: {}",
-                self.path_str, value, n
-            ),
-            Some(v) => panic!("Expected number at {}, got {:?}", self.path_str, v),
-            None => panic!("No value found at {}", self.path_str),
+    /// - Panics if the values differ, listing each difference
+    pub fn equals_with_diff(&mut self, expected: Value) -> &mut Self {
+        let actual = match self.first_value() {
+            Some(actual) => actual,
+            None => fail_with!(self, self.missing_error(Some(expected))),
+        };
+
+        let differences = json_diff(actual, &expected, &self.path_str);
+        if !differences.is_empty() {
+            fail!(
+                self,
+                "Value mismatch at {} ({} difference{})\n{}",
+                self.path_str,
+                differences.len(),
+                if differences.len() == 1 { "" } else { "s" },
+                differences.join("\n")
+            );
         }
+        self
     }
 
-    /// Asserts that the numeric value is less than the given value.
+    /// Asserts that the value equals the snapshot stored as `tests/snapshots/<name>.json`.
+    ///
+    /// The snapshot directory is resolved against `CARGO_MANIFEST_DIR`, which
+    /// cargo sets when running tests, or the current directory otherwise. On a
+    /// mismatch every differing path is listed, as in `equals_with_diff`.
+    ///
+    /// When the `UPDATE_SNAPSHOTS` environment variable is `1`, the current value
+    /// is written to the snapshot file instead, creating the directory if needed,
+    /// and the assertion passes. Review and commit the updated files like code.
     ///
     /// # Examples
     ///
-    /// ```rust
+    /// ```rust,no_run
     /// # use json_test::JsonTest;
     /// # use serde_json::json;
-    /// # let data = json!({"temperature": 36});
-    /// # let mut test = JsonTest::new(&data);
-    /// test.assert_path("$.temperature")
-    ///     .is_less_than(40);
+    /// # let data = json!({"user": {"name": "John"}});
+    /// # let test = JsonTest::new(&data);
+    /// // Compares against tests/snapshots/user.json
+    /// test.assert_path("$.user")
+    ///     .matches_snapshot("user");
     /// ```
     ///
     /// # Panics
     ///
     /// - Panics if no value exists at the path
-    /// - Panics if the value is not a number
-    /// - Panics if the value is not less than the given value
-    pub fn is_less_than(&'a mut self, value: i64) -> &'a mut Self {
-        match self.current_values.get(0) {
-            Some(Value::Number(n)) if n.as_i64().map_or(false, |x| x < value) => self,
-            Some(Value::Number(n)) => panic!(
-                "Number at {} is not less than {}\nActual: {}",
-                self.path_str, value, n
+    /// - Panics if the snapshot file is missing or is not valid JSON
+    /// - Panics if the value differs from the snapshot
+    /// - Panics if the snapshot cannot be written when updating
+    pub fn matches_snapshot(&mut self, name: &str) -> &mut Self {
+        let root = env::var_os("CARGO_MANIFEST_DIR").map_or_else(PathBuf::new, PathBuf::from);
+        let update = env::var("UPDATE_SNAPSHOTS").is_ok_and(|v| v == "1");
+        self.matches_snapshot_in(&root.join("tests").join("snapshots"), name, update)
+    }
+
+    fn matches_snapshot_in(&mut self, dir: &Path, name: &str, update: bool) -> &mut Self {
+        let actual = match self.first_value() {
+            Some(actual) => actual,
+            None => fail_with!(self, self.missing_error(None)),
+        };
+        let file = dir.join(format!("{}.json", name));
+
+        if update {
+            let contents = serde_json::to_string_pretty(actual)
+                .unwrap_or_else(|e| panic!("Failed to serialize snapshot {}: {}", file.display(), e));
+            fs::create_dir_all(dir)
+                .and_then(|_| fs::write(&file, contents + "\n"))
+                .unwrap_or_else(|e| panic!("Failed to write snapshot {}: {}", file.display(), e));
+            return self;
+        }
+
+        let contents = match fs::read_to_string(&file) {
+            Ok(contents) => contents,
+            Err(e) => fail!(
+                self,
+                "Failed to read snapshot {}: {}\nRun with UPDATE_SNAPSHOTS=1 to create it",
+                file.display(), e
             ),
-            Some(v) => panic!("Expected number at {}, got {:?}", self.path_str, v),
-            None => panic!("No value found at {}", self.path_str),
+        };
+        let expected: Value = match serde_json::from_str(&contents) {
+            Ok(expected) => expected,
+            Err(e) => fail!(self, "Invalid JSON in snapshot {}: {}", file.display(), e),
+        };
+
+        let differences = json_diff(actual, &expected, &self.path_str);
+        if !differences.is_empty() {
+            fail!(
+                self,
+                "Value at {} does not match snapshot {} ({} difference{})\n{}\nRun with UPDATE_SNAPSHOTS=1 to accept the new value",
+                self.path_str,
+                file.display(),
+                differences.len(),
+                if differences.len() == 1 { "" } else { "s" },
+                differences.join("\n")
+            );
         }
+        self
     }
 
-    /// Asserts that the numeric value is between the given minimum and maximum values (inclusive).
+    /// Asserts that the values selected by the path equal the expected values, ignoring order.
+    ///
+    /// Unlike `equals`, which only inspects the first match, this compares every
+    /// value selected by a multi-value expression such as a union (`$.user['name','email']`)
+    /// or a wildcard (`$.users[*].name`). Values are compared as a multiset, so
+    /// duplicates must appear the same number of times on both sides.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use json_test::JsonTest;
     /// # use serde_json::json;
-    /// # let data = json!({"score": 85});
-    /// # let mut test = JsonTest::new(&data);
-    /// test.assert_path("$.score")
-    ///     .is_between(0, 100);
+    /// # let data = json!({"user": {"name": "John", "email": "john@example.com", "age": 30}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.user['name','email']")
+    ///     .values_equal(&[json!("john@example.com"), json!("John")]);
     /// ```
     ///
     /// # Panics
     ///
-    /// - Panics if no value exists at the path
-    /// - Panics if the value is not a number
-    /// - Panics if the value is not between min and max (inclusive)
-    pub fn is_between(&'a mut self, min: i64, max: i64) -> &'a mut Self {
-        match self.current_values.get(0) {
-            Some(Value::Number(n)) if n.as_i64().map_or(false, |x| x >= min && x <= max) => self,
-            Some(Value::Number(n)) => panic!(
-                "Number at {} is not between {} and {}\nActual: {}",
-                self.path_str, min, max, n
-            ),
-            Some(v) => panic!("Expected number at {}, got {:?}", self.path_str, v),
-            None => panic!("No value found at {}", self.path_str),
+    /// Panics if the selected values differ from the expected values, listing
+    /// both the missing and the unexpected ones.
+    pub fn values_equal(&mut self, expected: &[Value]) -> &mut Self {
+        let (missing, unexpected) = multiset_difference(self.current_values.iter().map(Cow::as_ref), expected);
+        if !missing.is_empty() || !unexpected.is_empty() {
+            fail!(
+                self,
+                "Values at {} do not match\nMissing: {:?}\nUnexpected: {:?}\nActual: {:?}",
+                self.path_str, missing, unexpected, self.current_values
+            );
         }
+        self
     }
 
-    /// Asserts that the value at the current path is an array.
+    /// Asserts that the value equals one of the allowed values.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use json_test::JsonTest;
     /// # use serde_json::json;
-    /// # let data = json!({"tags": ["rust", "testing"]});
-    /// # let mut test = JsonTest::new(&data);
-    /// test.assert_path("$.tags")
-    ///     .is_array();
+    /// # let data = json!({"order": {"status": "shipped"}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.order.status")
+    ///     .is_one_of(&[json!("pending"), json!("shipped"), json!("delivered")]);
     /// ```
     ///
     /// # Panics
     ///
     /// - Panics if no value exists at the path
-    /// - Panics if the value is not an array
-    pub fn is_array(&'a mut self) -> &'a mut Self {
-        match self.current_values.get(0) {
-            Some(Value::Array(_)) => self,
-            Some(v) => panic!("Expected array at {}, got {:?}", self.path_str, v),
-            None => panic!("No value found at {}", self.path_str),
+    /// - Panics if the value equals none of the allowed values
+    pub fn is_one_of(&mut self, allowed: &[Value]) -> &mut Self {
+        match self.first_value() {
+            Some(value) if allowed.contains(value) => self,
+            Some(value) => fail!(
+                self,
+                "Value at {} is not one of {}\nActual: {}",
+                self.path_str, Value::Array(allowed.to_vec()), value
+            ),
+            None => fail_with!(self, self.missing_error(None)),
         }
     }
 
-    /// Asserts that the array has the expected length.
+    /// Asserts that the value equals none of the disallowed values.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use json_test::JsonTest;
     /// # use serde_json::json;
-    /// # let data = json!({"tags": ["rust", "testing"]});
-    /// # let mut test = JsonTest::new(&data);
-    /// test.assert_path("$.tags")
-    ///     .is_array()
-    ///     .has_length(2);
+    /// # let data = json!({"order": {"status": "shipped"}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.order.status")
+    ///     .is_not_one_of(&[json!("cancelled"), json!("refunded")]);
     /// ```
     ///
     /// # Panics
     ///
     /// - Panics if no value exists at the path
-    /// - Panics if the value is not an array
-    /// - Panics if the array length doesn't match the expected length
-    pub fn has_length(&'a mut self, expected: usize) -> &'a mut Self {
-        match self.current_values.get(0) {
-            Some(Value::Array(arr)) if arr.len() == expected => self,
-            Some(Value::Array(arr)) => panic!(
-                "Array at {} has wrong length\nExpected: {}\nActual: {}",
-                self.path_str, expected, arr.len()
+    /// - Panics if the value equals any of the disallowed values
+    pub fn is_not_one_of(&mut self, disallowed: &[Value]) -> &mut Self {
+        match self.first_value() {
+            Some(value) if disallowed.contains(value) => fail!(
+                self,
+                "Value at {} is unexpectedly one of {}\nActual: {}",
+                self.path_str, Value::Array(disallowed.to_vec()), value
             ),
-            Some(v) => panic!("Expected array at {}, got {:?}", self.path_str, v),
-            None => panic!("No value found at {}", self.path_str),
+            Some(_) => self,
+            None => fail_with!(self, self.missing_error(None)),
         }
     }
 
-    /// Asserts that the array contains the expected value.
+    /// Asserts that the array equals `expected`, ignoring element order.
+    ///
+    /// The arrays are compared as multisets: every element must appear the
+    /// same number of times in both. Only the top-level order is ignored;
+    /// nested arrays are compared with plain equality. If either value is not
+    /// an array, this behaves like `equals`.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use json_test::JsonTest;
     /// # use serde_json::json;
-    /// # let data = json!({"roles": ["user", "admin"]});
-    /// # let mut test = JsonTest::new(&data);
+    /// # let data = json!({"roles": ["editor", "admin", "user"]});
+    /// # let test = JsonTest::new(&data);
     /// test.assert_path("$.roles")
-    ///     .is_array()
-    ///     .contains(&json!("admin"));
+    ///     .equals_unordered(json!(["admin", "user", "editor"]));
     /// ```
     ///
     /// # Panics
     ///
     /// - Panics if no value exists at the path
-    /// - Panics if the value is not an array
-    /// - Panics if the array does not contain the expected value
-    pub fn contains(&'a mut self, expected: &Value) -> &'a mut Self {
-        match self.current_values.get(0) {
-            Some(Value::Array(arr)) if arr.contains(expected) => self,
-            Some(Value::Array(arr)) => panic!(
-                "Array at {} does not contain expected value\nExpected: {}\nArray: {:?}",
-                self.path_str, expected, arr
-            ),
-            Some(v) => panic!("Expected array at {}, got {:?}", self.path_str, v),
-            None => panic!("No value found at {}", self.path_str),
+    /// - Panics if the arrays differ, listing the missing and unexpected elements
+    /// - Panics if the values are not both arrays and are not equal
+    pub fn equals_unordered(&mut self, expected: Value) -> &mut Self {
+        let (actual, expected_arr) = match (self.first_value(), &expected) {
+            (Some(Value::Array(actual)), Value::Array(expected_arr)) => (actual, expected_arr),
+            _ => {
+                if let Err(error) = self.check_equals(&expected) {
+                    fail_with!(self, error);
+                }
+                return self;
+            }
+        };
+
+        let (missing, unexpected) = multiset_difference(actual, expected_arr);
+        if !missing.is_empty() || !unexpected.is_empty() {
+            let to_json = |values: Vec<&Value>| Value::Array(values.into_iter().cloned().collect());
+            fail!(
+                self,
+                "Array at {} does not match ignoring order\nMissing: {}\nUnexpected: {}\nActual: {}",
+                self.path_str, to_json(missing), to_json(unexpected), Value::Array(actual.clone())
+            );
         }
+        self
     }
 
-    /// Asserts that the value matches a custom predicate.
-    ///
-    /// This method allows for complex value validation using custom logic.
+    /// Asserts that the value at the current path is a string.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use json_test::JsonTest;
     /// # use serde_json::json;
-    /// # let data = json!({"timestamp": "2024-01-01T12:00:00Z"});
-    /// # let mut test = JsonTest::new(&data);
-    /// test.assert_path("$.timestamp")
-    ///     .matches(|value| {
-    ///         value.as_str()
-    ///             .map(|s| s.contains("T") && s.ends_with("Z"))
-    ///             .unwrap_or(false)
-    ///     });
+    /// # let data = json!({"message": "Hello"});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.message")
+    ///     .is_string();
     /// ```
     ///
     /// # Panics
     ///
     /// - Panics if no value exists at the path
-    /// - Panics if the value doesn't satisfy the predicate
-    pub fn matches<F>(&'a mut self, predicate: F) -> &'a mut Self
-    where
-        F: FnOnce(&Value) -> bool,
-    {
-        match self.current_values.get(0) {
-            Some(value) if predicate(value) => self,
-            Some(value) => panic!(
-                "Value at {} does not match predicate\nActual value: {}",
-                self.path_str, value
-            ),
-            None => panic!("No value found at {}", self.path_str),
+    /// - Panics if the value is not a string
+    pub fn is_string(&mut self) -> &mut Self {
+        if let Err(error) = self.check_type("string", Value::is_string) {
+            fail_with!(self, error);
         }
+        self
     }
 
-    /// Asserts that the value is an object and returns it for further testing.
+    /// Asserts that the string has exactly `expected` characters.
     ///
-    /// This method is primarily used internally by property assertions.
+    /// Characters are Unicode scalar values, not bytes, so `"héllo"` has length 5.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use json_test::JsonTest;
     /// # use serde_json::json;
-    /// # let data = json!({"user": {"name": "John", "age": 30}});
-    /// # let mut test = JsonTest::new(&data);
-    /// let obj = test.assert_path("$.user")
-    ///     .assert_object();
-    /// assert!(obj.contains_key("name"));
+    /// # let data = json!({"code": "héllo"});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.code")
+    ///     .has_string_length(5);
     /// ```
     ///
     /// # Panics
     ///
     /// - Panics if no value exists at the path
-    /// - Panics if the value is not an object
-    pub fn assert_object(&self) -> Map<String, Value> {
-        match &self.current_values[..] {
-            [Value::Object(obj)] => obj.clone(),
-            _ => panic!(
-                "Expected object at {}, got: {:?}",
-                self.path_str, self.current_values
+    /// - Panics if the value is not a string
+    /// - Panics if the character count doesn't match
+    pub fn has_string_length(&mut self, expected: usize) -> &mut Self {
+        self.check_string_length(expected, expected, expected.to_string())
+    }
+
+    /// Asserts that the string has between `min` and `max` characters (inclusive).
+    ///
+    /// Characters are counted as in `has_string_length`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"username": "john_doe"});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.username")
+    ///     .has_string_length_between(3, 20);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a string
+    /// - Panics if the character count is outside the range
+    pub fn has_string_length_between(&mut self, min: usize, max: usize) -> &mut Self {
+        self.check_string_length(min, max, format!("between {} and {}", min, max))
+    }
+
+    fn check_string_length(&mut self, min: usize, max: usize, expected: String) -> &mut Self {
+        let s = match self.first_value() {
+            Some(Value::String(s)) => s,
+            Some(v) => fail!(self, "Expected string at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        };
+
+        let count = s.chars().count();
+        if !(min..=max).contains(&count) {
+            fail!(
+                self,
+                "String at {} has wrong length\nExpected: {} characters\nActual: {} characters in {:?}",
+                self.path_str, expected, count, s
+            );
+        }
+        self
+    }
+
+    /// Asserts that the string value contains the given substring.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"email": "test@example.com"});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.email")
+    ///     .contains_string("@example");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a string
+    /// - Panics if the string does not contain the substring
+    pub fn contains_string(&mut self, substring: &str) -> &mut Self {
+        match self.first_value() {
+            Some(Value::String(s)) if s.contains(substring) => self,
+            Some(Value::String(s)) => fail!(
+                self,
+                "String at {} does not contain '{}'\nActual: {}",
+                self.path_str, substring, s
             ),
+            Some(v) => fail!(self, "Expected string at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
         }
     }
 
-    /// Creates a new assertion for a different path while maintaining the test context.
+    /// Asserts that the string equals `expected`, ignoring case.
     ///
-    /// This method enables chaining assertions across different paths.
+    /// Both sides are lowercased with `str::to_lowercase` before comparing. This
+    /// is simple lowercasing, not full Unicode case folding, so `"STRASSE"` and
+    /// `"straße"` are still different.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use json_test::{JsonTest, PropertyAssertions};
+    /// # use json_test::JsonTest;
     /// # use serde_json::json;
-    /// # let data = json!({
-    /// #     "user": {"name": "John"},
-    /// #     "settings": {"theme": "dark"}
-    /// # });
-    /// # let mut test = JsonTest::new(&data);
-    /// test.assert_path("$.user")
-    ///     .has_property("name")
-    ///     .assert_path("$.settings")
-    ///     .has_property("theme");
+    /// # let data = json!({"role": "Admin"});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.role")
+    ///     .equals_ignore_case("admin");
     /// ```
     ///
     /// # Panics
     ///
-    /// - Panics if called on an assertion without test context
-    pub fn assert_path(&'a mut self, path: &str) -> JsonPathAssertion<'a> {
-        match &mut self.test {
-            Some(test) => test.assert_path(path),
-            None => panic!("Cannot chain assertions without JsonTest context"),
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a string
+    /// - Panics if the lowercased strings differ
+    pub fn equals_ignore_case(&mut self, expected: &str) -> &mut Self {
+        match self.first_value() {
+            Some(Value::String(s)) if s.to_lowercase() == expected.to_lowercase() => self,
+            Some(Value::String(s)) => fail!(
+                self,
+                "String at {} does not equal '{}' ignoring case\nActual: {}",
+                self.path_str, expected, s
+            ),
+            Some(v) => fail!(self, "Expected string at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        }
+    }
+
+    /// Asserts that the string contains the given substring, ignoring case.
+    ///
+    /// Uses the same simple lowercasing as `equals_ignore_case`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"message": "Access DENIED for user"});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.message")
+    ///     .contains_string_ignore_case("denied");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a string
+    /// - Panics if the lowercased string does not contain the lowercased substring
+    pub fn contains_string_ignore_case(&mut self, substring: &str) -> &mut Self {
+        match self.first_value() {
+            Some(Value::String(s)) if s.to_lowercase().contains(&substring.to_lowercase()) => self,
+            Some(Value::String(s)) => fail!(
+                self,
+                "String at {} does not contain '{}' ignoring case\nActual: {}",
+                self.path_str, substring, s
+            ),
+            Some(v) => fail!(self, "Expected string at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        }
+    }
+
+    /// Asserts that the string value starts with the given prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"id": "user_123"});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.id")
+    ///     .starts_with("user_");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a string
+    /// - Panics if the string does not start with the prefix
+    pub fn starts_with(&mut self, prefix: &str) -> &mut Self {
+        match self.first_value() {
+            Some(Value::String(s)) if s.starts_with(prefix) => self,
+            Some(Value::String(s)) => fail!(
+                self,
+                "String at {} does not start with '{}'\nActual: {}",
+                self.path_str, prefix, s
+            ),
+            Some(v) => fail!(self, "Expected string at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        }
+    }
+
+    /// Asserts that the string value ends with the given suffix.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"file": "document.pdf"});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.file")
+    ///     .ends_with(".pdf");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a string
+    /// - Panics if the string does not end with the suffix
+    pub fn ends_with(&mut self, suffix: &str) -> &mut Self {
+        match self.first_value() {
+            Some(Value::String(s)) if s.ends_with(suffix) => self,
+            Some(Value::String(s)) => fail!(
+                self,
+                "String at {} does not end with '{}'\nActual: {}",
+                self.path_str, suffix, s
+            ),
+            Some(v) => fail!(self, "Expected string at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        }
+    }
+
+    /// Asserts that the string value matches the given regular expression pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"email": "test@example.com"});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.email")
+    ///     .matches_pattern(r"^[^@]+@[^@]+\.[^@]+$");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a string
+    /// - Panics if the pattern is invalid
+    /// - Panics if the string does not match the pattern
+    pub fn matches_pattern(&mut self, pattern: &str) -> &mut Self {
+        let regex = regex::Regex::new(pattern)
+            .unwrap_or_else(|e| panic!("Invalid regex pattern: {}", e));
+
+        match self.first_value() {
+            Some(Value::String(s)) if regex.is_match(s) => self,
+            Some(Value::String(s)) => fail!(
+                self,
+                "String at {} does not match pattern '{}'\nActual: {}",
+                self.path_str, pattern, s
+            ),
+            Some(v) => fail!(self, "Expected string at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        }
+    }
+
+    /// Asserts that the string matches the pattern compiled with the given flags.
+    ///
+    /// This avoids embedding inline flags such as `(?i)` in the pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::{JsonTest, RegexFlags};
+    /// # use serde_json::json;
+    /// # let data = json!({"log": "Starting\nERROR: disk full"});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.log")
+    ///     .matches_pattern_with_flags(
+    ///         r"^error:",
+    ///         RegexFlags::new().case_insensitive(true).multiline(true),
+    ///     );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a string
+    /// - Panics if the pattern is invalid
+    /// - Panics if the string does not match the pattern
+    pub fn matches_pattern_with_flags(&mut self, pattern: &str, flags: RegexFlags) -> &mut Self {
+        let regex = flags.build(pattern)
+            .unwrap_or_else(|e| panic!("Invalid regex pattern: {}", e));
+
+        match self.first_value() {
+            Some(Value::String(s)) if regex.is_match(s) => self,
+            Some(Value::String(s)) => fail!(
+                self,
+                "String at {} does not match pattern '{}' with flags: {}\nActual: {}",
+                self.path_str, pattern, flags, s
+            ),
+            Some(v) => fail!(self, "Expected string at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        }
+    }
+
+    /// Matches the string against a regular expression and returns its capture groups.
+    ///
+    /// Use `group` or `named_group` on the result to continue asserting on a
+    /// captured substring.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"created_at": "2024-03-15T10:30:00Z"});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.created_at")
+    ///     .captures(r"(\d{4})-\d{2}")
+    ///     .group(1)
+    ///     .equals(json!("2024"));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the pattern is invalid
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a string
+    /// - Panics if the string does not match the pattern
+    pub fn captures(&mut self, pattern: &str) -> CaptureAssertion<'_, 'a> {
+        CaptureAssertion::new(self, pattern)
+    }
+
+    /// Asserts that the string is itself a valid regular expression.
+    ///
+    /// Useful for configuration that carries patterns to be compiled later.
+    /// The pattern is checked with the same `regex` syntax used by `matches_pattern`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"rules": [{"pattern": "^order-[0-9]+$"}]});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.rules[0].pattern")
+    ///     .is_valid_regex();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a string
+    /// - Panics if the string does not compile as a regular expression
+    pub fn is_valid_regex(&mut self) -> &mut Self {
+        match self.first_value() {
+            Some(Value::String(s)) => match regex::Regex::new(s) {
+                Ok(_) => self,
+                Err(e) => fail!(
+                    self,
+                    "String at {} is not a valid regex: {}\nActual: {}",
+                    self.path_str, e, s
+                ),
+            },
+            Some(v) => fail!(self, "Expected string at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        }
+    }
+
+    /// Asserts that the string value contains no line breaks (`\n` or `\r`).
+    ///
+    /// Useful for single-line fields such as log messages or header values,
+    /// where an injected newline can break downstream protocols.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"header": "text/plain; charset=utf-8"});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.header")
+    ///     .has_no_newlines();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a string
+    /// - Panics if the string contains a `\n` or `\r`
+    pub fn has_no_newlines(&mut self) -> &mut Self {
+        match self.first_value() {
+            Some(Value::String(s)) => match s.find(['\n', '\r']) {
+                Some(pos) => fail!(
+                    self,
+                    "String at {} contains a newline at byte {}\nActual: {:?}",
+                    self.path_str, pos, s
+                ),
+                None => self,
+            },
+            Some(v) => fail!(self, "Expected string at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
         }
     }
-}
\ No newline at end of file
+
+    /// Asserts that the string value is a single line.
+    ///
+    /// This is an alias for [`has_no_newlines`](Self::has_no_newlines).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"message": "Request completed"});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.message")
+    ///     .is_single_line();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a string
+    /// - Panics if the string contains a `\n` or `\r`
+    pub fn is_single_line(&mut self) -> &mut Self {
+        self.has_no_newlines()
+    }
+
+    /// Asserts that the string value contains no U+FFFD replacement characters.
+    ///
+    /// Replacement characters are what lossy UTF-8 decoding substitutes for
+    /// invalid byte sequences, so their presence points at an upstream decode error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"title": "Crème brûlée"});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.title")
+    ///     .has_no_replacement_chars();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a string
+    /// - Panics if the string contains `\u{FFFD}`
+    pub fn has_no_replacement_chars(&mut self) -> &mut Self {
+        match self.first_value() {
+            Some(Value::String(s)) if s.contains(char::REPLACEMENT_CHARACTER) => fail!(
+                self,
+                "String at {} contains replacement character(s) indicating decode errors\nActual: {:?}",
+                self.path_str, s
+            ),
+            Some(Value::String(_)) => self,
+            Some(v) => fail!(self, "Expected string at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        }
+    }
+
+    /// Asserts that the string is a hex color in `#RGB`, `#RRGGBB` or `#RRGGBBAA` form.
+    ///
+    /// Hex digits are accepted in either case.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"theme": {"primary": "#1E90FF", "overlay": "#00000080", "text": "#fff"}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.theme.primary").is_hex_color();
+    /// test.assert_path("$.theme.overlay").is_hex_color();
+    /// test.assert_path("$.theme.text").is_hex_color();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a string
+    /// - Panics if the string is not a valid hex color
+    pub fn is_hex_color(&mut self) -> &mut Self {
+        match self.first_value() {
+            Some(Value::String(s)) => {
+                let valid = s.strip_prefix('#').is_some_and(|hex| {
+                    matches!(hex.len(), 3 | 6 | 8) && hex.chars().all(|c| c.is_ascii_hexdigit())
+                });
+                if !valid {
+                    fail!(self, "String at {} is not a valid hex color\nActual: {}", self.path_str, s);
+                }
+                self
+            }
+            Some(v) => fail!(self, "Expected string at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        }
+    }
+
+    /// Asserts that the value is a valid pagination cursor: absent, `null`, or a
+    /// non-empty base64 string.
+    ///
+    /// Both the standard and the URL-safe base64 alphabets are accepted, with or
+    /// without `=` padding. An empty string fails, which catches APIs that send
+    /// `""` instead of `null` on the last page.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"page": {"next_cursor": "eyJpZCI6NDJ9", "prev_cursor": null}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.page.next_cursor").is_cursor();
+    /// test.assert_path("$.page.prev_cursor").is_cursor();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is present and is neither `null` nor a non-empty
+    /// base64 string.
+    pub fn is_cursor(&mut self) -> &mut Self {
+        match self.first_value() {
+            None | Some(Value::Null) => self,
+            Some(Value::String(s)) if is_base64(s) => self,
+            Some(v) => fail!(
+                self,
+                "Cursor at {} is invalid (empty or not base64)\nActual: {}",
+                self.path_str, v
+            ),
+        }
+    }
+
+    /// Asserts that the string is a calendar date in `YYYY-MM-DD` form.
+    ///
+    /// This matches JSON Schema's `format: date`. Month and day ranges are
+    /// checked, including leap years, so `2024-02-29` passes while `2023-02-29`
+    /// and `2024-13-40` do not.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"user": {"birth_date": "1990-07-14"}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.user.birth_date")
+    ///     .is_date();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a string
+    /// - Panics if the string is not a valid date
+    pub fn is_date(&mut self) -> &mut Self {
+        if let Err(error) = self.parse_date("a valid date") {
+            fail_with!(self, error);
+        }
+        self
+    }
+
+    /// Asserts that the string is a date strictly before `other` (`YYYY-MM-DD`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"trial": {"ends_on": "2024-03-01"}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.trial.ends_on")
+    ///     .date_is_before("2024-12-31");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `other` is not a valid date
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a valid date string
+    /// - Panics if the date is not before `other`
+    pub fn date_is_before(&mut self, other: &str) -> &mut Self {
+        let reference = parse_date(other)
+            .unwrap_or_else(|| panic!("Invalid reference date '{}', expected YYYY-MM-DD", other));
+        let (date, s) = ok_or_fail!(self, self.parse_date("a valid date"));
+        if date >= reference {
+            fail!(self, "Date at {} is not before {}\nActual: {}", self.path_str, other, s);
+        }
+        self
+    }
+
+    /// Asserts that the string is a date strictly after `other` (`YYYY-MM-DD`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"trial": {"ends_on": "2024-03-01"}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.trial.ends_on")
+    ///     .date_is_after("2024-02-29");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `other` is not a valid date
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a valid date string
+    /// - Panics if the date is not after `other`
+    pub fn date_is_after(&mut self, other: &str) -> &mut Self {
+        let reference = parse_date(other)
+            .unwrap_or_else(|| panic!("Invalid reference date '{}', expected YYYY-MM-DD", other));
+        let (date, s) = ok_or_fail!(self, self.parse_date("a valid date"));
+        if date <= reference {
+            fail!(self, "Date at {} is not after {}\nActual: {}", self.path_str, other, s);
+        }
+        self
+    }
+
+    /// Asserts that the string is an ISO 8601 calendar date in extended form (`YYYY-MM-DD`).
+    ///
+    /// This accepts the same strings as `is_date`, but the failure message
+    /// names the ISO 8601 format. Basic (`YYYYMMDD`), week and ordinal dates are
+    /// not accepted. Parsing is done by this crate, without `chrono` or `time`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"invoice": {"due_date": "2024-02-29"}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.invoice.due_date")
+    ///     .is_iso8601_date();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a string
+    /// - Panics if the string is not a valid ISO 8601 date
+    pub fn is_iso8601_date(&mut self) -> &mut Self {
+        ok_or_fail!(self, self.parse_date("an ISO 8601 date"));
+        self
+    }
+
+    /// Asserts that the string is an RFC 3339 date-time.
+    ///
+    /// The accepted form is `YYYY-MM-DDTHH:MM:SS`, an optional fraction of a
+    /// second, and a `Z` or `±HH:MM` offset, for example `2024-03-15T10:30:00Z`
+    /// or `2024-03-15T10:30:00.250+01:00`. `T` and `Z` may be lowercase and a
+    /// leap second (`:60`) is allowed. A missing offset or a space instead of
+    /// `T` is rejected. Parsing is done by this crate, without `chrono` or `time`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"event": {"created_at": "2024-03-15T10:30:00.250+01:00"}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.event.created_at")
+    ///     .is_rfc3339_datetime();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a string
+    /// - Panics if the string is not a valid RFC 3339 date-time
+    pub fn is_rfc3339_datetime(&mut self) -> &mut Self {
+        ok_or_fail!(self, self.parse_datetime());
+        self
+    }
+
+    /// Asserts that the string is an RFC 3339 date-time between `min` and `max` (inclusive).
+    ///
+    /// Timestamps are compared as instants, so offsets are taken into account:
+    /// `2024-03-15T12:00:00+02:00` equals `2024-03-15T10:00:00Z`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"event": {"created_at": "2024-03-15T12:00:00+02:00"}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.event.created_at")
+    ///     .is_rfc3339_datetime_between("2024-03-15T00:00:00Z", "2024-03-15T10:00:00Z");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `min` or `max` is not a valid RFC 3339 date-time
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a valid RFC 3339 date-time string
+    /// - Panics if the timestamp is outside the range
+    pub fn is_rfc3339_datetime_between(&mut self, min: &str, max: &str) -> &mut Self {
+        let bound = |bound: &str| parse_rfc3339(bound)
+            .unwrap_or_else(|| panic!("Invalid RFC 3339 bound '{}', expected YYYY-MM-DDTHH:MM:SSZ", bound));
+        let (lower, upper) = (bound(min), bound(max));
+        let (instant, s) = ok_or_fail!(self, self.parse_datetime());
+
+        if instant < lower || instant > upper {
+            fail!(
+                self,
+                "Timestamp at {} is not between {} and {}\nActual: {}",
+                self.path_str, min, max, s
+            );
+        }
+        self
+    }
+
+    /// Asserts that the string is `"true"` or `"false"`.
+    ///
+    /// Use `is_boolean_string_ignore_case` to also accept forms such as `"True"`
+    /// or `"FALSE"`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"form": {"subscribe": "true"}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.form.subscribe")
+    ///     .is_boolean_string();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a string
+    /// - Panics if the string is not `"true"` or `"false"`
+    pub fn is_boolean_string(&mut self) -> &mut Self {
+        if let Err(error) = self.parse_boolean_string(false) {
+            fail_with!(self, error);
+        }
+        self
+    }
+
+    /// Asserts that the string is `"true"` or `"false"`, ignoring ASCII case.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"form": {"subscribe": "True"}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.form.subscribe")
+    ///     .is_boolean_string_ignore_case();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a string
+    /// - Panics if the string is not `"true"` or `"false"` in any case
+    pub fn is_boolean_string_ignore_case(&mut self) -> &mut Self {
+        if let Err(error) = self.parse_boolean_string(true) {
+            fail_with!(self, error);
+        }
+        self
+    }
+
+    /// Asserts that the string is a boolean string holding the expected value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"form": {"subscribe": "false"}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.form.subscribe")
+    ///     .boolean_string_equals(false);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a string
+    /// - Panics if the string is not `"true"` or `"false"`
+    /// - Panics if the parsed boolean differs from `expected`
+    pub fn boolean_string_equals(&mut self, expected: bool) -> &mut Self {
+        let actual = ok_or_fail!(self, self.parse_boolean_string(false));
+        if actual != expected {
+            fail!(
+                self,
+                "Boolean string at {} does not match\nExpected: {}\nActual: {}",
+                self.path_str, expected, actual
+            );
+        }
+        self
+    }
+
+    /// Asserts that the value at the current path is a number.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"count": 42});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.count")
+    ///     .is_number();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a number
+    pub fn is_number(&mut self) -> &mut Self {
+        if let Err(error) = self.check_type("number", Value::is_number) {
+            fail_with!(self, error);
+        }
+        self
+    }
+
+    /// Asserts that the number is stored as an integer.
+    ///
+    /// This checks the representation: `42` passes, while `42.5` and `42.0`
+    /// fail because they are parsed as floating-point numbers. Use
+    /// `is_integer_valued` to accept `42.0` as well.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"id": 42});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.id")
+    ///     .is_integer();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a number
+    /// - Panics if the number is a floating-point value
+    pub fn is_integer(&mut self) -> &mut Self {
+        if let Err(error) = self.check_integer() {
+            fail_with!(self, error);
+        }
+        self
+    }
+
+    /// Asserts that the number is a floating-point value with a fractional part.
+    ///
+    /// Integers such as `42` fail, and so do floating-point values that are
+    /// representable as integers, such as `42.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"price": 19.99});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.price")
+    ///     .is_float();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a number
+    /// - Panics if the number is an integer or integer-valued
+    pub fn is_float(&mut self) -> &mut Self {
+        if let Err(error) = self.check_float() {
+            fail_with!(self, error);
+        }
+        self
+    }
+
+    /// Asserts that the number has no fractional part, however it is written.
+    ///
+    /// Both `2` and `2.0` pass, while `2.5` fails. This looks at the numeric
+    /// value only; `equals` and `is_integer` instead check representations, so
+    /// `json!(2)` and `json!(2.0)` are not equal there and `2.0` is not an integer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"quantity": 2.0});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.quantity")
+    ///     .is_integer_valued();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a number
+    /// - Panics if the number has a fractional part
+    pub fn is_integer_valued(&mut self) -> &mut Self {
+        match self.first_value() {
+            Some(Value::Number(n)) if is_whole_number(n) => self,
+            Some(Value::Number(n)) => fail!(
+                self,
+                "Number at {} is not integer-valued\nActual: {}",
+                self.path_str, n
+            ),
+            Some(v) => fail!(self, "Expected number at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        }
+    }
+
+    /// Asserts that the number equals `expected` numerically.
+    ///
+    /// Unlike `equals`, the representation does not matter: `2` and `2.0` both
+    /// equal `2.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"a": 2, "b": 2.0});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.a").equals_number(2.0);
+    /// test.assert_path("$.b").equals_number(2.0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a number
+    /// - Panics if the number differs from `expected`
+    pub fn equals_number(&mut self, expected: f64) -> &mut Self {
+        match self.first_value() {
+            Some(Value::Number(n)) if n.as_f64() == Some(expected) => self,
+            Some(Value::Number(n)) => fail!(
+                self,
+                "Value mismatch at {}\nExpected: {}\nActual: {}",
+                self.path_str, expected, n
+            ),
+            Some(v) => fail!(self, "Expected number at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        }
+    }
+
+    /// Asserts that the number is within `epsilon` of `expected`.
+    ///
+    /// Use this for computed floating-point values, where `equals` is defeated
+    /// by representation error such as `0.1 + 0.2` being `0.30000000000000004`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"total": 0.1 + 0.2});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.total")
+    ///     .is_close_to(0.3, 1e-9);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a number
+    /// - Panics if `epsilon` is NaN or negative
+    /// - Panics if the absolute difference exceeds `epsilon`, or is NaN
+    pub fn is_close_to(&mut self, expected: f64, epsilon: f64) -> &mut Self {
+        if epsilon.is_nan() || epsilon < 0.0 {
+            panic!("Invalid epsilon {}: must be a non-negative number", epsilon);
+        }
+        let actual = match self.first_value() {
+            Some(Value::Number(n)) => n.as_f64().unwrap_or_default(),
+            Some(v) => fail!(self, "Expected number at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        };
+
+        // Only a difference within epsilon passes, so a NaN difference fails.
+        let difference = (actual - expected).abs();
+        if difference <= epsilon {
+            return self;
+        }
+        fail!(
+            self,
+            "Number at {} is not close to {}\nExpected: {} (epsilon {})\nActual: {}\nDifference: {}",
+            self.path_str, expected, expected, epsilon, actual, difference
+        );
+    }
+
+    /// Asserts that the number is strictly greater than zero.
+    ///
+    /// Integers and floats are both accepted. Zero, including `-0.0`, is not
+    /// positive; use `is_greater_than_or_equal(0)` to allow it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"balance": 0.01});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.balance")
+    ///     .is_positive();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a number
+    /// - Panics if the number is zero or negative
+    pub fn is_positive(&mut self) -> &mut Self {
+        self.check_sign("positive", |x| x > 0.0)
+    }
+
+    /// Asserts that the number is strictly less than zero.
+    ///
+    /// Integers and floats are both accepted. Zero, including `-0.0`, is not
+    /// negative.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"refund": -25});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.refund")
+    ///     .is_negative();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a number
+    /// - Panics if the number is zero or positive
+    pub fn is_negative(&mut self) -> &mut Self {
+        self.check_sign("negative", |x| x < 0.0)
+    }
+
+    /// Asserts that the number is zero.
+    ///
+    /// `0`, `0.0` and `-0.0` all pass.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"balance": 0});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.balance")
+    ///     .is_zero();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a number
+    /// - Panics if the number is not zero
+    pub fn is_zero(&mut self) -> &mut Self {
+        self.check_sign("zero", |x| x == 0.0)
+    }
+
+    fn check_sign(&mut self, description: &str, predicate: fn(f64) -> bool) -> &mut Self {
+        match self.first_value() {
+            Some(Value::Number(n)) if n.as_f64().is_some_and(predicate) => self,
+            Some(Value::Number(n)) => fail!(
+                self,
+                "Number at {} is not {}\nActual: {}",
+                self.path_str, description, n
+            ),
+            Some(v) => fail!(self, "Expected number at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        }
+    }
+
+    /// Asserts that the value has the given JSON Schema type.
+    ///
+    /// Accepts the JSON Schema type names `"integer"`, `"number"`, `"string"`,
+    /// `"boolean"`, `"array"`, `"object"` and `"null"`. As in JSON Schema,
+    /// `"integer"` matches numbers without a fractional part (including `1.0`),
+    /// while `"number"` matches any number.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"id": 42, "score": 9.5, "tags": []});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.id").is_schema_type("integer");
+    /// test.assert_path("$.score").is_schema_type("number");
+    /// test.assert_path("$.tags").is_schema_type("array");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `schema_type` is not a JSON Schema type name
+    /// - Panics if no value exists at the path
+    /// - Panics if the value does not have the given type
+    pub fn is_schema_type(&mut self, schema_type: &str) -> &mut Self {
+        let is_integer = |v: &Value| matches!(v, Value::Number(n) if is_whole_number(n));
+        let check: fn(&Value) -> bool = match schema_type {
+            "integer" => is_integer,
+            "number" => Value::is_number,
+            "string" => Value::is_string,
+            "boolean" => Value::is_boolean,
+            "array" => Value::is_array,
+            "object" => Value::is_object,
+            "null" => Value::is_null,
+            other => panic!("Unknown JSON Schema type '{}'", other),
+        };
+
+        let value = match self.first_value() {
+            Some(v) => v,
+            None => fail_with!(self, self.missing_error(None)),
+        };
+
+        if !check(value) {
+            let actual = if is_integer(value) {
+                "integer".to_string()
+            } else {
+                type_name(value)
+            };
+            fail!(
+                self,
+                "Value at {} is not schema type '{}'\nActual type: {}",
+                self.path_str, schema_type, actual
+            );
+        }
+        self
+    }
+
+    /// Asserts that the numeric value is greater than the given value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"age": 21});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.age")
+    ///     .is_greater_than(18);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a number
+    /// - Panics if the value is not greater than the given value
+    pub fn is_greater_than(&mut self, value: i64) -> &mut Self {
+        if let Err(error) = self.check_greater_than(value) {
+            fail_with!(self, error);
+        }
+        self
+    }
+
+    /// Asserts that the numeric value is less than the given value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"temperature": 36});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.temperature")
+    ///     .is_less_than(40);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a number
+    /// - Panics if the value is not less than the given value
+    pub fn is_less_than(&mut self, value: i64) -> &mut Self {
+        if let Err(error) = self.check_less_than(value) {
+            fail_with!(self, error);
+        }
+        self
+    }
+
+    /// Asserts that the numeric value is greater than or equal to the given value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"age": 18});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.age")
+    ///     .is_greater_than_or_equal(18);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a number
+    /// - Panics if the value is not greater than or equal to the given value
+    pub fn is_greater_than_or_equal(&mut self, value: i64) -> &mut Self {
+        if let Err(error) = self.check_at_least(value) {
+            fail_with!(self, error);
+        }
+        self
+    }
+
+    /// Asserts that the numeric value is less than or equal to the given value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"score": 100});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.score")
+    ///     .is_less_than_or_equal(100);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a number
+    /// - Panics if the value is not less than or equal to the given value
+    pub fn is_less_than_or_equal(&mut self, value: i64) -> &mut Self {
+        if let Err(error) = self.check_at_most(value) {
+            fail_with!(self, error);
+        }
+        self
+    }
+
+    /// Asserts that the numeric value is greater than or equal to the given value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"ratio": 0.5});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.ratio")
+    ///     .is_greater_than_or_equal_f64(0.0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a number
+    /// - Panics if the value is not greater than or equal to the given value
+    pub fn is_greater_than_or_equal_f64(&mut self, value: f64) -> &mut Self {
+        if let Err(error) = self.check_at_least_f64(value) {
+            fail_with!(self, error);
+        }
+        self
+    }
+
+    /// Asserts that the numeric value is less than or equal to the given value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"ratio": 1.0});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.ratio")
+    ///     .is_less_than_or_equal_f64(1.0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a number
+    /// - Panics if the value is not less than or equal to the given value
+    pub fn is_less_than_or_equal_f64(&mut self, value: f64) -> &mut Self {
+        if let Err(error) = self.check_at_most_f64(value) {
+            fail_with!(self, error);
+        }
+        self
+    }
+
+    /// Asserts that the numeric value is between the given minimum and maximum values (inclusive).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"score": 85});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.score")
+    ///     .is_between(0, 100);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a number
+    /// - Panics if the value is not between min and max (inclusive)
+    pub fn is_between(&mut self, min: i64, max: i64) -> &mut Self {
+        if let Err(error) = self.check_between(min, max) {
+            fail_with!(self, error);
+        }
+        self
+    }
+
+    /// Asserts that the string parses as an integer between `min` and `max` (inclusive).
+    ///
+    /// Intended for loosely typed APIs that send numbers as strings.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"quantity": "12"});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.quantity")
+    ///     .parses_as_i64_between(1, 100);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a string
+    /// - Panics if the string is not a valid integer
+    /// - Panics if the parsed integer is outside the range
+    pub fn parses_as_i64_between(&mut self, min: i64, max: i64) -> &mut Self {
+        let s = match self.first_value() {
+            Some(Value::String(s)) => s,
+            Some(v) => fail!(self, "Expected string at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        };
+        let parsed: i64 = match s.parse() {
+            Ok(parsed) => parsed,
+            Err(_) => fail!(self, "String at {} ('{}') is not a valid integer", self.path_str, s),
+        };
+
+        if parsed < min || parsed > max {
+            fail!(
+                self,
+                "Integer parsed from string at {} is not between {} and {}\nActual: {}",
+                self.path_str, min, max, parsed
+            );
+        }
+        self
+    }
+
+    /// Asserts that the string parses as a floating-point number between `min` and `max` (inclusive).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"price": "19.99"});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.price")
+    ///     .parses_as_f64_between(0.0, 100.0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a string
+    /// - Panics if the string is not a valid number
+    /// - Panics if the parsed number is outside the range
+    pub fn parses_as_f64_between(&mut self, min: f64, max: f64) -> &mut Self {
+        let s = match self.first_value() {
+            Some(Value::String(s)) => s,
+            Some(v) => fail!(self, "Expected string at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        };
+        let parsed: f64 = match s.parse() {
+            Ok(parsed) => parsed,
+            Err(_) => fail!(self, "String at {} ('{}') is not a valid number", self.path_str, s),
+        };
+
+        if !(min..=max).contains(&parsed) {
+            fail!(
+                self,
+                "Number parsed from string at {} is not between {} and {}\nActual: {}",
+                self.path_str, min, max, parsed
+            );
+        }
+        self
+    }
+
+    /// Asserts that the string parses as a number.
+    ///
+    /// Integers, decimals and exponents such as `"1e3"` are accepted; `"NaN"`
+    /// and infinities are not, since JSON cannot represent them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"balance": "-12.50"});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.balance")
+    ///     .parses_as_number();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a string
+    /// - Panics if the string is not a valid number
+    pub fn parses_as_number(&mut self) -> &mut Self {
+        ok_or_fail!(self, self.parse_number());
+        self
+    }
+
+    /// Asserts that the string parses as a number equal to `expected`.
+    ///
+    /// The comparison is numeric, so `"42"`, `"42.0"` and `"4.2e1"` all equal `42.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"price": "19.99"});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.price")
+    ///     .parses_as_number_equals(19.99);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a string
+    /// - Panics if the string is not a valid number
+    /// - Panics if the parsed number does not equal `expected`
+    pub fn parses_as_number_equals(&mut self, expected: f64) -> &mut Self {
+        let (parsed, s) = ok_or_fail!(self, self.parse_number());
+
+        if parsed != expected {
+            fail!(
+                self,
+                "Number parsed from string at {} does not equal {}\nActual: {} (from '{}')",
+                self.path_str, expected, parsed, s
+            );
+        }
+        self
+    }
+
+    /// Asserts that the number is a Unix timestamp in seconds within `within` of now.
+    ///
+    /// Fractional seconds are allowed. Use `is_epoch_seconds_near` to compare
+    /// against a fixed reference time in deterministic tests.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # use std::time::{Duration, SystemTime, UNIX_EPOCH};
+    /// # let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+    /// # let data = json!({"created_at": now});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.created_at")
+    ///     .is_recent_epoch_seconds(Duration::from_secs(60));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a number
+    /// - Panics if the timestamp is further than `within` from now
+    pub fn is_recent_epoch_seconds(&mut self, within: Duration) -> &mut Self {
+        self.epoch_within(1.0, SystemTime::now(), "now", within)
+    }
+
+    /// Asserts that the number is a Unix timestamp in milliseconds within `within` of now.
+    ///
+    /// Use `is_epoch_millis_near` to compare against a fixed reference time in
+    /// deterministic tests.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # use std::time::{Duration, SystemTime, UNIX_EPOCH};
+    /// # let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64;
+    /// # let data = json!({"updated_at": now});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.updated_at")
+    ///     .is_recent_epoch_millis(Duration::from_secs(60));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a number
+    /// - Panics if the timestamp is further than `within` from now
+    pub fn is_recent_epoch_millis(&mut self, within: Duration) -> &mut Self {
+        self.epoch_within(1000.0, SystemTime::now(), "now", within)
+    }
+
+    /// Asserts that the number is a Unix timestamp in seconds within `within` of `reference`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # use std::time::{Duration, UNIX_EPOCH};
+    /// # let data = json!({"created_at": 1700000030});
+    /// # let test = JsonTest::new(&data);
+    /// let reference = UNIX_EPOCH + Duration::from_secs(1700000000);
+    /// test.assert_path("$.created_at")
+    ///     .is_epoch_seconds_near(reference, Duration::from_secs(60));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a number
+    /// - Panics if the timestamp is further than `within` from `reference`
+    pub fn is_epoch_seconds_near(&mut self, reference: SystemTime, within: Duration) -> &mut Self {
+        self.epoch_within(1.0, reference, "the reference time", within)
+    }
+
+    /// Asserts that the number is a Unix timestamp in milliseconds within `within` of `reference`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # use std::time::{Duration, UNIX_EPOCH};
+    /// # let data = json!({"updated_at": 1700000000500u64});
+    /// # let test = JsonTest::new(&data);
+    /// let reference = UNIX_EPOCH + Duration::from_secs(1700000000);
+    /// test.assert_path("$.updated_at")
+    ///     .is_epoch_millis_near(reference, Duration::from_secs(1));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a number
+    /// - Panics if the timestamp is further than `within` from `reference`
+    pub fn is_epoch_millis_near(&mut self, reference: SystemTime, within: Duration) -> &mut Self {
+        self.epoch_within(1000.0, reference, "the reference time", within)
+    }
+
+    fn epoch_within(
+        &mut self,
+        units_per_second: f64,
+        reference: SystemTime,
+        reference_name: &str,
+        within: Duration,
+    ) -> &mut Self {
+        let timestamp = match self.first_value() {
+            Some(Value::Number(n)) => n.as_f64().unwrap_or(f64::NAN) / units_per_second,
+            Some(v) => fail!(self, "Expected number at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        };
+        let reference = match reference.duration_since(UNIX_EPOCH) {
+            Ok(since) => since.as_secs_f64(),
+            Err(e) => -e.duration().as_secs_f64(),
+        };
+
+        let delta = Duration::try_from_secs_f64((timestamp - reference).abs()).unwrap_or(Duration::MAX);
+        if delta > within {
+            fail!(
+                self,
+                "Epoch timestamp at {} is {:?} from {}, exceeding {:?}",
+                self.path_str, delta, reference_name, within
+            );
+        }
+        self
+    }
+
+    /// Asserts that the ratio of this number to the number at another path lies
+    /// between `min` and `max` (inclusive).
+    ///
+    /// The denominator path is resolved from the document root, which makes
+    /// derived metrics such as success rates easy to bound.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"success": 995, "total": 1000});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.success")
+    ///     .ratio_with_path_between("$.total", 0.99, 1.0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if either value is missing or is not a number
+    /// - Panics if the denominator is zero
+    /// - Panics if the ratio is outside the range
+    /// - Panics if called on an assertion without test context
+    pub fn ratio_with_path_between(&mut self, denominator_path: &str, min: f64, max: f64) -> &mut Self {
+        let numerator = match self.first_value() {
+            Some(Value::Number(n)) => n.as_f64().unwrap_or(f64::NAN),
+            Some(v) => fail!(self, "Expected number at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        };
+        let denominator = match self.resolve_from_root(denominator_path).first().map(Cow::as_ref) {
+            Some(Value::Number(n)) => n.as_f64().unwrap_or(f64::NAN),
+            Some(v) => fail!(self, "Expected number at {}, got {:?}", denominator_path, v),
+            None => fail!(self, "No value found at {}", denominator_path),
+        };
+
+        if denominator == 0.0 {
+            fail!(
+                self,
+                "Cannot compute ratio {}/{}: denominator at {} is zero",
+                self.path_str, denominator_path, denominator_path
+            );
+        }
+
+        let ratio = numerator / denominator;
+        if !(min..=max).contains(&ratio) {
+            fail!(
+                self,
+                "Ratio {}/{} = {} not in [{}, {}]",
+                self.path_str, denominator_path, ratio, min, max
+            );
+        }
+        self
+    }
+
+    /// Asserts that the value at the current path is an array.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"tags": ["rust", "testing"]});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.tags")
+    ///     .is_array();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not an array
+    pub fn is_array(&mut self) -> &mut Self {
+        if let Err(error) = self.check_type("array", Value::is_array) {
+            fail_with!(self, error);
+        }
+        self
+    }
+
+    /// Asserts that the value at the current path is an object.
+    ///
+    /// Unlike `assert_object`, this keeps the chain going, so property
+    /// assertions can follow directly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::{JsonTest, PropertyAssertions};
+    /// # use serde_json::json;
+    /// # let data = json!({"user": {"name": "John"}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.user")
+    ///     .is_object()
+    ///     .has_property("name");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not an object
+    pub fn is_object(&mut self) -> &mut Self {
+        if let Err(error) = self.check_type("object", Value::is_object) {
+            fail_with!(self, error);
+        }
+        self
+    }
+
+    /// Asserts that the array has the expected length.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"tags": ["rust", "testing"]});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.tags")
+    ///     .is_array()
+    ///     .has_length(2);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not an array
+    /// - Panics if the array length doesn't match the expected length
+    pub fn has_length(&mut self, expected: usize) -> &mut Self {
+        match self.first_value() {
+            Some(Value::Array(arr)) if arr.len() == expected => self,
+            Some(Value::Array(arr)) => fail!(
+                self,
+                "Array at {} has wrong length\nExpected: {}\nActual: {}",
+                self.path_str, expected, arr.len()
+            ),
+            Some(v) => fail!(self, "Expected array at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        }
+    }
+
+    /// Asserts that the array length is between `min` and `max` (inclusive).
+    ///
+    /// Like `has_length`, this only applies to arrays; use `length_equals` for
+    /// the length of strings and objects.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"results": [1, 2, 3]});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.results")
+    ///     .has_length_between(1, 10);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not an array
+    /// - Panics if the array length is outside the range
+    pub fn has_length_between(&mut self, min: usize, max: usize) -> &mut Self {
+        self.check_length_bounds(min, max, format!("between {} and {}", min, max))
+    }
+
+    /// Asserts that the array has at least `min` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"results": [1, 2, 3]});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.results")
+    ///     .has_length_at_least(1);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not an array
+    /// - Panics if the array has fewer than `min` elements
+    pub fn has_length_at_least(&mut self, min: usize) -> &mut Self {
+        self.check_length_bounds(min, usize::MAX, format!("at least {}", min))
+    }
+
+    /// Asserts that the array has at most `max` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"page": [1, 2, 3]});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.page")
+    ///     .has_length_at_most(25);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not an array
+    /// - Panics if the array has more than `max` elements
+    pub fn has_length_at_most(&mut self, max: usize) -> &mut Self {
+        self.check_length_bounds(0, max, format!("at most {}", max))
+    }
+
+    fn check_length_bounds(&mut self, min: usize, max: usize, expected: String) -> &mut Self {
+        match self.first_value() {
+            Some(Value::Array(arr)) if (min..=max).contains(&arr.len()) => self,
+            Some(Value::Array(arr)) => fail!(
+                self,
+                "Array at {} has wrong length\nExpected: {}\nActual: {}",
+                self.path_str, expected, arr.len()
+            ),
+            Some(v) => fail!(self, "Expected array at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        }
+    }
+
+    /// Returns the number of elements in the array.
+    ///
+    /// Unlike the JSONPath `length()` function, which not every engine supports,
+    /// this works on any path that selects an array.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"items": [1, 2, 3]});
+    /// # let test = JsonTest::new(&data);
+    /// let len = test.assert_path("$.items").array_length();
+    /// assert_eq!(len, 3);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not an array
+    pub fn array_length(&self) -> usize {
+        match self.first_value() {
+            Some(Value::Array(arr)) => arr.len(),
+            Some(v) => self.panic_with(self.failure(format!("Expected array at {}, got {:?}", self.path_str, v))),
+            None => self.no_value(),
+        }
+    }
+
+    /// Returns the number of properties in the object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"user": {"name": "John", "age": 30}});
+    /// # let test = JsonTest::new(&data);
+    /// let size = test.assert_path("$.user").object_size();
+    /// assert_eq!(size, 2);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not an object
+    pub fn object_size(&self) -> usize {
+        match self.first_value() {
+            Some(Value::Object(obj)) => obj.len(),
+            Some(v) => self.panic_with(self.failure(format!("Expected object at {}, got {:?}", self.path_str, v))),
+            None => self.no_value(),
+        }
+    }
+
+    /// Asserts that the array has the same length as the array at another path.
+    ///
+    /// The other path is resolved from the document root, so parallel arrays
+    /// such as chart `labels` and `values` can be checked in one call.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// let data = json!({
+    ///     "chart": {"labels": ["Mon", "Tue", "Wed"], "values": [3, 5, 2]}
+    /// });
+    ///
+    /// let test = JsonTest::new(&data);
+    /// test.assert_path("$.chart.labels")
+    ///     .array_length_equals_path("$.chart.values");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if either value is missing or is not an array
+    /// - Panics if the lengths differ
+    /// - Panics if called on an assertion without test context
+    pub fn array_length_equals_path(&mut self, other_path: &str) -> &mut Self {
+        let len = match self.first_value() {
+            Some(Value::Array(arr)) => arr.len(),
+            Some(v) => fail!(self, "Expected array at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        };
+        let other_len = match self.resolve_from_root(other_path).first().map(Cow::as_ref) {
+            Some(Value::Array(other)) => other.len(),
+            Some(v) => fail!(self, "Expected array at {}, got {:?}", other_path, v),
+            None => fail!(self, "No value found at {}", other_path),
+        };
+
+        if len != other_len {
+            fail!(
+                self,
+                "Array at {} has length {}, but array at {} has length {}",
+                self.path_str, len, other_path, other_len
+            );
+        }
+        self
+    }
+
+    /// Asserts that the length of the value equals `expected`.
+    ///
+    /// The length depends on the type of the value:
+    ///
+    /// - strings: the number of characters (Unicode scalar values)
+    /// - arrays: the number of elements
+    /// - objects: the number of properties
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"code": "héllo", "tags": ["a", "b"], "user": {"name": "John"}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.code").length_equals(5);
+    /// test.assert_path("$.tags").length_equals(2);
+    /// test.assert_path("$.user").length_equals(1);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a string, array or object
+    /// - Panics if the length doesn't match
+    pub fn length_equals(&mut self, expected: usize) -> &mut Self {
+        let (kind, actual) = match self.first_value() {
+            Some(Value::String(s)) => ("String", s.chars().count()),
+            Some(Value::Array(arr)) => ("Array", arr.len()),
+            Some(Value::Object(obj)) => ("Object", obj.len()),
+            Some(v) => fail!(self, "Expected string, array or object at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        };
+
+        if actual != expected {
+            fail!(
+                self,
+                "{} at {} has wrong length\nExpected: {}\nActual: {}",
+                kind, self.path_str, expected, actual
+            );
+        }
+        self
+    }
+
+    /// Asserts that the array contains the expected value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"roles": ["user", "admin"]});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.roles")
+    ///     .is_array()
+    ///     .contains(&json!("admin"));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not an array
+    /// - Panics if the array does not contain the expected value
+    pub fn contains(&mut self, expected: &Value) -> &mut Self {
+        match self.first_value() {
+            Some(Value::Array(arr)) if arr.contains(expected) => self,
+            Some(Value::Array(arr)) => fail!(
+                self,
+                "Array at {} does not contain expected value\nExpected: {}\nArray: {:?}",
+                self.path_str, expected, arr
+            ),
+            Some(v) => fail!(self, "Expected array at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        }
+    }
+
+    /// Asserts that the array contains every expected value.
+    ///
+    /// Unlike repeated `contains` calls, a failure lists all missing values at once.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"roles": ["user", "editor", "admin"]});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.roles")
+    ///     .contains_all(&[json!("admin"), json!("user")]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not an array
+    /// - Panics if any expected value is missing from the array
+    pub fn contains_all(&mut self, expected: &[Value]) -> &mut Self {
+        let arr = match self.first_value() {
+            Some(Value::Array(arr)) => arr,
+            Some(v) => fail!(self, "Expected array at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        };
+
+        let missing: Vec<Value> = expected.iter()
+            .filter(|value| !arr.contains(value))
+            .cloned()
+            .collect();
+        if !missing.is_empty() {
+            fail!(
+                self,
+                "Array at {} is missing values: {}\nArray: {:?}",
+                self.path_str, Value::Array(missing), arr
+            );
+        }
+        self
+    }
+
+    /// Asserts that the array does not contain the given value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"roles": ["user", "editor"]});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.roles")
+    ///     .does_not_contain(&json!("admin"));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not an array
+    /// - Panics if the array contains the value
+    pub fn does_not_contain(&mut self, value: &Value) -> &mut Self {
+        match self.first_value() {
+            Some(Value::Array(arr)) if !arr.contains(value) => self,
+            Some(Value::Array(arr)) => fail!(
+                self,
+                "Array at {} unexpectedly contains {}\nArray: {:?}",
+                self.path_str, value, arr
+            ),
+            Some(v) => fail!(self, "Expected array at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        }
+    }
+
+    /// Asserts that no two elements of the array are equal.
+    ///
+    /// Elements are compared with `Value` equality, so `1` and `1.0` are distinct.
+    /// Since `Value` is not `Hash`, every pair is compared: O(n²) in the array length.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"ids": [3, 1, 2]});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.ids")
+    ///     .has_unique_elements();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not an array
+    /// - Panics if any element appears more than once, listing each duplicate and its indices
+    pub fn has_unique_elements(&mut self) -> &mut Self {
+        let arr = match self.first_value() {
+            Some(Value::Array(arr)) => arr,
+            Some(v) => fail!(self, "Expected array at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        };
+
+        let mut occurrences: Vec<(&Value, Vec<usize>)> = Vec::new();
+        for (index, element) in arr.iter().enumerate() {
+            match occurrences.iter_mut().find(|(value, _)| *value == element) {
+                Some((_, indices)) => indices.push(index),
+                None => occurrences.push((element, vec![index])),
+            }
+        }
+
+        let duplicates: Vec<String> = occurrences.iter()
+            .filter(|(_, indices)| indices.len() > 1)
+            .map(|(value, indices)| format!("{} at indices {:?}", value, indices))
+            .collect();
+        if !duplicates.is_empty() {
+            fail!(
+                self,
+                "Array at {} has duplicate elements:\n{}",
+                self.path_str, duplicates.join("\n")
+            );
+        }
+        self
+    }
+
+    /// Asserts that the array element at `index` equals the expected value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"steps": ["build", "test", "deploy"]});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.steps")
+    ///     .index_equals(0, json!("build"))
+    ///     .index_equals(2, json!("deploy"));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not an array
+    /// - Panics if `index` is out of bounds
+    /// - Panics if the element doesn't equal the expected value
+    pub fn index_equals(&mut self, index: usize, expected: Value) -> &mut Self {
+        let arr = match self.first_value() {
+            Some(Value::Array(arr)) => arr,
+            Some(v) => fail!(self, "Expected array at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        };
+
+        match arr.get(index) {
+            Some(actual) if actual == &expected => self,
+            Some(actual) => fail!(
+                self,
+                "Element {} of {} mismatch\nExpected: {}\nActual: {}",
+                index, self.path_str, expected, actual
+            ),
+            None => fail!(
+                self,
+                "Index {} out of bounds for array at {} with length {}",
+                index, self.path_str, arr.len()
+            ),
+        }
+    }
+
+    /// Creates an assertion scoped to the array element at `index`.
+    ///
+    /// The new assertion's path is `<path>[<index>]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"roles": ["admin", "editor"]});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.roles")
+    ///     .nth(1)
+    ///     .equals(json!("editor"));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not an array
+    /// - Panics if `index` is out of bounds
+    pub fn nth(&mut self, index: usize) -> JsonPathAssertion<'a> {
+        match self.first_value() {
+            Some(Value::Array(arr)) if index >= arr.len() => self.report(format!(
+                "Index {} out of bounds for array at {} with length {}",
+                index, self.path_str, arr.len()
+            )),
+            Some(Value::Array(_)) => {}
+            Some(v) => self.report(format!("Expected array at {}, got {:?}", self.path_str, v)),
+            None => self.report_error(self.missing_error(None)),
+        }
+
+        self.child(format!("{}[{}]", self.path_str, index), |value| value.get(index))
+    }
+
+    /// Creates an assertion scoped to the first element of the array.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"steps": ["build", "test", "deploy"]});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.steps")
+    ///     .first()
+    ///     .equals(json!("build"));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not an array
+    /// - Panics if the array is empty
+    pub fn first(&mut self) -> JsonPathAssertion<'a> {
+        self.nth(0)
+    }
+
+    /// Creates an assertion scoped to the last element of the array.
+    ///
+    /// The new assertion's path uses the element's index, e.g. `$.steps[2]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"steps": ["build", "test", "deploy"]});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.steps")
+    ///     .last()
+    ///     .equals(json!("deploy"));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not an array
+    /// - Panics if the array is empty
+    pub fn last(&mut self) -> JsonPathAssertion<'a> {
+        let index = match self.first_value() {
+            Some(Value::Array(arr)) => arr.len().saturating_sub(1),
+            _ => 0,
+        };
+        self.nth(index)
+    }
+
+    /// Asserts that every element of the array satisfies a predicate.
+    ///
+    /// An empty array passes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"items": [{"price": 10}, {"price": 25}]});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.items")
+    ///     .all_match(|item| item["price"].as_f64().is_some_and(|price| price > 0.0));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not an array
+    /// - Panics on the first element that does not satisfy the predicate
+    pub fn all_match<F>(&mut self, predicate: F) -> &mut Self
+    where
+        F: Fn(&Value) -> bool,
+    {
+        let arr = match self.first_value() {
+            Some(Value::Array(arr)) => arr,
+            Some(v) => fail!(self, "Expected array at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        };
+
+        if let Some((index, element)) = arr.iter().enumerate().find(|(_, element)| !predicate(element)) {
+            fail!(
+                self,
+                "Element at index {} of {} does not match predicate\nActual: {}",
+                index, self.path_str, element
+            );
+        }
+        self
+    }
+
+    /// Asserts that at least one element of the array satisfies a predicate.
+    ///
+    /// An empty array fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"users": [{"role": "user"}, {"role": "admin"}]});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.users")
+    ///     .any_match(|user| user["role"] == "admin");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not an array
+    /// - Panics if no element satisfies the predicate
+    pub fn any_match<F>(&mut self, predicate: F) -> &mut Self
+    where
+        F: Fn(&Value) -> bool,
+    {
+        match self.first_value() {
+            Some(Value::Array(arr)) if arr.iter().any(predicate) => self,
+            Some(Value::Array(arr)) => fail!(
+                self,
+                "No element of {} matches predicate\nArray: {:?}",
+                self.path_str, arr
+            ),
+            Some(v) => fail!(self, "Expected array at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        }
+    }
+
+    /// Asserts that every sliding window of `size` consecutive elements satisfies a predicate.
+    ///
+    /// Useful for invariants between neighbouring elements, such as monotonic
+    /// sequences or bounded step sizes. Arrays shorter than `size` have no windows
+    /// and pass trivially.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"prices": [100, 105, 110, 115]});
+    /// # let test = JsonTest::new(&data);
+    /// // Each price is at most 10% higher than the previous one
+    /// test.assert_path("$.prices")
+    ///     .windows_satisfy(2, |w| w[1].as_f64().unwrap() <= w[0].as_f64().unwrap() * 1.1);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `size` is zero
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not an array
+    /// - Panics if any window fails the predicate
+    pub fn windows_satisfy<F>(&mut self, size: usize, predicate: F) -> &mut Self
+    where
+        F: Fn(&[Value]) -> bool,
+    {
+        if size == 0 {
+            fail!(self, "Window size for {} must be greater than zero", self.path_str);
+        }
+        let arr = match self.first_value() {
+            Some(Value::Array(arr)) => arr,
+            Some(v) => fail!(self, "Expected array at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        };
+
+        if let Some((i, window)) = arr.windows(size).enumerate().find(|(_, w)| !predicate(w)) {
+            fail!(
+                self,
+                "Window at index {} of {} failed predicate: {:?}",
+                i, self.path_str, window
+            );
+        }
+        self
+    }
+
+    /// Asserts that an array of numbers or strings is in ascending order.
+    ///
+    /// Adjacent elements are compared; equal neighbours are allowed. Numbers are
+    /// compared numerically and strings lexicographically. Use `is_sorted_by` to
+    /// order arrays of objects by a computed key.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"versions": [1, 2, 2, 5], "names": ["Alice", "Bob"]});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.versions")
+    ///     .is_sorted();
+    /// test.assert_path("$.names")
+    ///     .is_sorted();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not an array
+    /// - Panics if the elements are not all numbers or all strings
+    /// - Panics if any element is greater than the one after it
+    pub fn is_sorted(&mut self) -> &mut Self {
+        self.check_sorted(true)
+    }
+
+    /// Asserts that an array of numbers or strings is in descending order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"scores": [98.5, 87, 87, 42]});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.scores")
+    ///     .is_sorted_descending();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not an array
+    /// - Panics if the elements are not all numbers or all strings
+    /// - Panics if any element is less than the one after it
+    pub fn is_sorted_descending(&mut self) -> &mut Self {
+        self.check_sorted(false)
+    }
+
+    fn check_sorted(&mut self, ascending: bool) -> &mut Self {
+        let arr = match self.first_value() {
+            Some(Value::Array(arr)) => arr,
+            Some(v) => fail!(self, "Expected array at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        };
+
+        let order = if ascending { "ascending" } else { "descending" };
+        for (i, pair) in arr.windows(2).enumerate() {
+            let ordering = match (&pair[0], &pair[1]) {
+                (Value::Number(a), Value::Number(b)) => a.as_f64().partial_cmp(&b.as_f64()),
+                (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+                _ => None,
+            };
+            let in_order = match ordering {
+                Some(Ordering::Equal) => true,
+                Some(ordering) => (ordering == Ordering::Less) == ascending,
+                None => fail!(
+                    self,
+                    "Array at {} has incomparable elements: {} {} at index {} and {} {} at index {}",
+                    self.path_str, type_name(&pair[0]), pair[0], i, type_name(&pair[1]), pair[1], i + 1
+                ),
+            };
+            if !in_order {
+                fail!(
+                    self,
+                    "Array at {} is not sorted in {} order: {} at index {} is followed by {} at index {}",
+                    self.path_str, order, pair[0], i, pair[1], i + 1
+                );
+            }
+        }
+        self
+    }
+
+    /// Asserts that the array is sorted by a key extracted from each element.
+    ///
+    /// `key_fn` computes the sort key, which can be a nested field or a value
+    /// derived from several fields. Equal keys are allowed next to each other.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"lines": [
+    /// #     {"price": 5.0, "quantity": 4},
+    /// #     {"price": 12.5, "quantity": 1},
+    /// #     {"price": 2.0, "quantity": 3}
+    /// # ]});
+    /// # let test = JsonTest::new(&data);
+    /// // Sorted by line total, highest first
+    /// test.assert_path("$.lines")
+    ///     .is_sorted_by(false, |line| Some(line["price"].as_f64()? * line["quantity"].as_f64()?));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not an array
+    /// - Panics if `key_fn` returns `None` for any element
+    /// - Panics if the keys are not in the requested order
+    pub fn is_sorted_by<F>(&mut self, ascending: bool, key_fn: F) -> &mut Self
+    where
+        F: Fn(&Value) -> Option<f64>,
+    {
+        let arr = match self.first_value() {
+            Some(Value::Array(arr)) => arr,
+            Some(v) => fail!(self, "Expected array at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        };
+
+        let mut keys = Vec::with_capacity(arr.len());
+        for (i, element) in arr.iter().enumerate() {
+            match key_fn(element) {
+                Some(key) => keys.push(key),
+                None => fail!(
+                    self,
+                    "Could not extract sort key from element at index {} of {}: {}",
+                    i, self.path_str, element
+                ),
+            }
+        }
+
+        let order = if ascending { "ascending" } else { "descending" };
+        for (i, pair) in keys.windows(2).enumerate() {
+            let in_order = if ascending { pair[0] <= pair[1] } else { pair[0] >= pair[1] };
+            if !in_order {
+                fail!(
+                    self,
+                    "Array at {} is not sorted in {} order: key {} at index {} is followed by {} at index {}",
+                    self.path_str, order, pair[0], i, pair[1], i + 1
+                );
+            }
+        }
+        self
+    }
+
+    /// Asserts that a field has the same type in every object of the array.
+    ///
+    /// Values may differ between elements, but their JSON types must not. Elements
+    /// missing the field are reported alongside the type mismatches.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"users": [{"id": 1}, {"id": 2}, {"id": 3}]});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.users")
+    ///     .field_type_consistent_across("id");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not an array
+    /// - Panics if any element is not an object
+    /// - Panics if the field is missing from some elements or has differing types
+    pub fn field_type_consistent_across(&mut self, key: &str) -> &mut Self {
+        let arr = match self.first_value() {
+            Some(Value::Array(arr)) => arr,
+            Some(v) => fail!(self, "Expected array at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        };
+
+        let mut first_seen: Vec<(String, usize)> = Vec::new();
+        let mut missing = Vec::new();
+        for (index, element) in arr.iter().enumerate() {
+            let obj = match element {
+                Value::Object(obj) => obj,
+                other => fail!(
+                    self,
+                    "Expected object at index {} of {}, got {:?}",
+                    index, self.path_str, other
+                ),
+            };
+            match obj.get(key) {
+                Some(value) => {
+                    let ty = type_name(value);
+                    if !first_seen.iter().any(|(seen, _)| seen == &ty) {
+                        first_seen.push((ty, index));
+                    }
+                }
+                None => missing.push(index.to_string()),
+            }
+        }
+
+        if first_seen.len() > 1 || !missing.is_empty() {
+            let mut parts: Vec<String> = first_seen
+                .iter()
+                .map(|(ty, index)| format!("{} at index {}", ty, index))
+                .collect();
+            if !missing.is_empty() {
+                parts.push(format!("missing at index {}", missing.join(", ")));
+            }
+            fail!(
+                self,
+                "Field '{}' has inconsistent types across array at {}: {}",
+                key, self.path_str, parts.join(", ")
+            );
+        }
+        self
+    }
+
+    /// Computes summary statistics for a numeric array.
+    ///
+    /// Returns a [`NumericStats`] with the minimum, maximum, sum, mean and count
+    /// of the elements, which supports its own chainable bound assertions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"scores": [1, 2, 3, 4]});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.scores")
+    ///     .numeric_stats()
+    ///     .mean_between(2.0, 3.0)
+    ///     .max_at_most(10.0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not an array, or is empty
+    /// - Panics if any element is not a number, naming its index
+    ///
+    /// There are no statistics to return when these checks fail, so inside
+    /// `JsonTest::assert_all` they abort the block rather than being recorded.
+    pub fn numeric_stats(&self) -> NumericStats {
+        let arr = match self.first_value() {
+            Some(Value::Array(arr)) => arr,
+            Some(v) => self.panic_with(self.failure(format!("Expected array at {}, got {:?}", self.path_str, v))),
+            None => self.no_value(),
+        };
+        if arr.is_empty() {
+            self.panic_with(self.failure(format!("Cannot compute statistics for empty array at {}", self.path_str)));
+        }
+
+        let numbers: Vec<f64> = arr
+            .iter()
+            .enumerate()
+            .map(|(index, element)| {
+                element.as_f64().unwrap_or_else(|| {
+                    self.panic_with(self.failure(format!(
+                        "Element at index {} of {} is not a number: {:?}",
+                        index, self.path_str, element
+                    )))
+                })
+            })
+            .collect();
+        NumericStats::from_values(&numbers, &self.path_str)
+    }
+
+    /// Asserts that every element of a numeric array lies between `min` and `max` (inclusive).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"probabilities": [0.1, 0.65, 0.25]});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.probabilities")
+    ///     .all_elements_between(0.0, 1.0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not an array
+    /// - Panics if any element is not a number
+    /// - Panics if any element is outside the range
+    pub fn all_elements_between(&mut self, min: f64, max: f64) -> &mut Self {
+        let arr = match self.first_value() {
+            Some(Value::Array(arr)) => arr,
+            Some(v) => fail!(self, "Expected array at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        };
+
+        for (i, element) in arr.iter().enumerate() {
+            match element.as_f64() {
+                Some(v) if (min..=max).contains(&v) => {}
+                Some(_) => fail!(
+                    self,
+                    "Element {} of {} ({}) is outside [{}, {}]",
+                    i, self.path_str, element, min, max
+                ),
+                None => fail!(
+                    self,
+                    "Element at index {} of {} is not a number: {:?}",
+                    i, self.path_str, element
+                ),
+            }
+        }
+        self
+    }
+
+    /// Asserts that the value is an array whose elements are all strings.
+    ///
+    /// An empty array passes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"tags": ["rust", "", "testing"]});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.tags")
+    ///     .is_string_array();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not an array
+    /// - Panics on the first element that is not a string
+    pub fn is_string_array(&mut self) -> &mut Self {
+        self.check_string_array(false)
+    }
+
+    /// Asserts that the value is a non-empty array of non-empty strings.
+    ///
+    /// This is the usual shape of tag and label lists.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"tags": ["rust", "testing"]});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.tags")
+    ///     .is_non_empty_string_array();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not an array or the array is empty
+    /// - Panics on the first element that is not a string or is an empty string
+    pub fn is_non_empty_string_array(&mut self) -> &mut Self {
+        self.check_string_array(true)
+    }
+
+    fn check_string_array(&mut self, non_empty: bool) -> &mut Self {
+        let arr = match self.first_value() {
+            Some(Value::Array(arr)) => arr,
+            Some(v) => fail!(self, "Expected array at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        };
+
+        if non_empty && arr.is_empty() {
+            fail!(self, "Expected non-empty array at {}, got []", self.path_str);
+        }
+        for (index, element) in arr.iter().enumerate() {
+            match element {
+                Value::String(s) if non_empty && s.is_empty() => fail!(
+                    self,
+                    "Element at index {} of {} is an empty string",
+                    index, self.path_str
+                ),
+                Value::String(_) => {}
+                other => fail!(
+                    self,
+                    "Element at index {} of {} is not a string: {:?}",
+                    index, self.path_str, other
+                ),
+            }
+        }
+        self
+    }
+
+    /// Asserts that the value is an array of booleans that are all `true`.
+    ///
+    /// An empty array passes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"checks": [true, true, true]});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.checks")
+    ///     .all_true();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not an array
+    /// - Panics on the first element that is `false` or not a boolean
+    pub fn all_true(&mut self) -> &mut Self {
+        self.all_booleans_equal(true)
+    }
+
+    /// Asserts that the value is an array of booleans that are all `false`.
+    ///
+    /// An empty array passes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"errors": [false, false]});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.errors")
+    ///     .all_false();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not an array
+    /// - Panics on the first element that is `true` or not a boolean
+    pub fn all_false(&mut self) -> &mut Self {
+        self.all_booleans_equal(false)
+    }
+
+    fn all_booleans_equal(&mut self, expected: bool) -> &mut Self {
+        let arr = match self.first_value() {
+            Some(Value::Array(arr)) => arr,
+            Some(v) => fail!(self, "Expected array at {}, got {:?}", self.path_str, v),
+            None => fail_with!(self, self.missing_error(None)),
+        };
+
+        for (index, element) in arr.iter().enumerate() {
+            match element {
+                Value::Bool(b) if *b == expected => {}
+                Value::Bool(b) => fail!(
+                    self,
+                    "Element at index {} of {} is {}, expected all {}",
+                    index, self.path_str, b, expected
+                ),
+                other => fail!(
+                    self,
+                    "Element at index {} of {} is not a boolean: {:?}",
+                    index, self.path_str, other
+                ),
+            }
+        }
+        self
+    }
+
+    /// Asserts that the value matches a custom predicate.
+    ///
+    /// This method allows for complex value validation using custom logic.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"timestamp": "2024-01-01T12:00:00Z"});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.timestamp")
+    ///     .matches(|value| {
+    ///         value.as_str()
+    ///             .map(|s| s.contains("T") && s.ends_with("Z"))
+    ///             .unwrap_or(false)
+    ///     });
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value doesn't satisfy the predicate
+    pub fn matches<F>(&mut self, predicate: F) -> &mut Self
+    where
+        F: FnOnce(&Value) -> bool,
+    {
+        match self.first_value() {
+            Some(value) if predicate(value) => self,
+            Some(value) => fail!(
+                self,
+                "Value at {} does not match predicate\nActual value: {}",
+                self.path_str, value
+            ),
+            None => fail_with!(self, self.missing_error(None)),
+        }
+    }
+
+    /// Asserts that the value matches a predicate that also receives the path.
+    ///
+    /// The path lets one predicate be shared between several locations and still
+    /// behave differently, or reason about where it is applied.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"limits": {"max_items": 100, "max_bytes_kb": 2048}});
+    /// # let test = JsonTest::new(&data);
+    /// let within_limit = |path: &str, value: &serde_json::Value| {
+    ///     let max = if path.ends_with("_kb") { 4096 } else { 500 };
+    ///     value.as_u64().is_some_and(|v| v <= max)
+    /// };
+    ///
+    /// test.assert_path("$.limits.max_items").matches_with_path(within_limit);
+    /// test.assert_path("$.limits.max_bytes_kb").matches_with_path(within_limit);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value doesn't satisfy the predicate
+    pub fn matches_with_path<F>(&mut self, predicate: F) -> &mut Self
+    where
+        F: FnOnce(&str, &Value) -> bool,
+    {
+        match self.first_value() {
+            Some(value) if predicate(&self.path_str, value) => self,
+            Some(value) => fail!(
+                self,
+                "Value at {} does not match path-aware predicate\nActual: {}",
+                self.path_str, value
+            ),
+            None => fail_with!(self, self.missing_error(None)),
+        }
+    }
+
+    /// Asserts that the value satisfies a matcher.
+    ///
+    /// Any `JsonMatcher` can be used, including custom matchers and matchers
+    /// composed with `AndMatcher`, `OrMatcher` and `NotMatcher`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::{AndMatcher, JsonTest, RegexMatcher, TypeMatcher};
+    /// # use serde_json::json;
+    /// # let data = json!({"user": {"id": "u_1024"}});
+    /// # let test = JsonTest::new(&data);
+    /// let user_id = AndMatcher::both(TypeMatcher::string(), RegexMatcher::new(r"^u_\d+$").unwrap());
+    /// test.assert_path("$.user.id")
+    ///     .satisfies(&user_id);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value does not satisfy the matcher
+    pub fn satisfies(&mut self, matcher: &dyn JsonMatcher) -> &mut Self {
+        match self.first_value() {
+            Some(value) if matcher.matches(value) => self,
+            Some(value) => fail!(
+                self,
+                "Value at {} does not satisfy: {}\nActual: {}",
+                self.path_str,
+                matcher.description(),
+                value
+            ),
+            None => fail_with!(self, self.missing_error(None)),
+        }
+    }
+
+    /// Asserts that the value does not satisfy a matcher.
+    ///
+    /// This negates any reusable `JsonMatcher` without wrapping it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::{JsonTest, RegexMatcher};
+    /// # use serde_json::json;
+    /// # let data = json!({"user": {"display_name": "John"}});
+    /// # let test = JsonTest::new(&data);
+    /// let email = RegexMatcher::new(r"^[^@]+@[^@]+$").unwrap();
+    /// test.assert_path("$.user.display_name")
+    ///     .does_not_satisfy(&email);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value satisfies the matcher
+    pub fn does_not_satisfy(&mut self, matcher: &dyn JsonMatcher) -> &mut Self {
+        match self.first_value() {
+            Some(value) if matcher.matches(value) => fail!(
+                self,
+                "Value at {} unexpectedly satisfies matcher: {}\nActual: {}",
+                self.path_str,
+                matcher.description(),
+                value
+            ),
+            Some(_) => self,
+            None => fail_with!(self, self.missing_error(None)),
+        }
+    }
+
+    /// Starts a chain of negated assertions on the current value.
+    ///
+    /// Methods on the returned `NegatedAssertion` pass when the positive
+    /// assertion of the same name would fail. Use `and()` to return to the
+    /// positive chain.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"user": {"email": "john@example.com", "roles": ["user"]}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.user.email")
+    ///     .not()
+    ///     .matches_pattern(r"@test\.");
+    /// test.assert_path("$.user.roles")
+    ///     .not()
+    ///     .contains(&json!("admin"));
+    /// ```
+    pub fn not(&mut self) -> NegatedAssertion<'_, 'a> {
+        NegatedAssertion::new(self)
+    }
+
+    /// Returns an `EachAssertion` that checks every value matched by the path.
+    ///
+    /// Use this with wildcard and filter paths, where the other assertions only
+    /// inspect the first match.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"users": [{"age": 25}, {"age": 30}]});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.users[*].age")
+    ///     .each()
+    ///     .is_greater_than(0);
+    /// ```
+    pub fn each(&mut self) -> EachAssertion<'_, 'a> {
+        EachAssertion::new(self)
+    }
+
+    /// Returns an iterator over every value matched by the path, in document order.
+    ///
+    /// This hands the matches to standard iterator combinators for checks that
+    /// are easier to write in plain Rust.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"users": [{"age": 17}, {"age": 30}, {"age": 42}]});
+    /// # let test = JsonTest::new(&data);
+    /// let adults = test.assert_path("$.users[*].age")
+    ///     .iter_matches()
+    ///     .filter(|age| age.as_u64() >= Some(18))
+    ///     .count();
+    /// assert_eq!(adults, 2);
+    /// ```
+    pub fn iter_matches(&self) -> impl Iterator<Item = &Value> {
+        self.current_values.iter().map(Cow::as_ref)
+    }
+
+    /// Returns a copy of the first matched value.
+    ///
+    /// This is an escape hatch for handing a located value to other code, such
+    /// as a decoder, and checking the result separately. The chain is not
+    /// affected, so assertions can continue on the same path.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"auth": {"token": "abc.def"}});
+    /// # let test = JsonTest::new(&data);
+    /// let token = test.assert_path("$.auth.token").extract();
+    /// assert_eq!(token.as_str().unwrap().split('.').count(), 2);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if no value exists at the path.
+    pub fn extract(&self) -> Value {
+        match self.first_value() {
+            Some(value) => value.clone(),
+            None => self.no_value(),
+        }
+    }
+
+    /// Asserts that the string holds a valid JSON document.
+    ///
+    /// Use this for payloads that embed JSON as a string field, such as
+    /// `"metadata": "{\"a\":1}"`. `embedded_json` continues the chain on the
+    /// parsed document and `parse_json` returns it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"metadata": "{\"source\": \"import\", \"rows\": 120}"});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.metadata")
+    ///     .is_valid_json();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a string
+    /// - Panics if the string is not valid JSON, with the parser's message
+    pub fn is_valid_json(&mut self) -> &mut Self {
+        ok_or_fail!(self, self.parse_embedded_json());
+        self
+    }
+
+    /// Returns the JSON document held by the string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"metadata": "{\"source\": \"import\", \"rows\": 120}"});
+    /// # let test = JsonTest::new(&data);
+    /// let metadata = test.assert_path("$.metadata").parse_json();
+    /// assert_eq!(metadata["rows"], json!(120));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a string
+    /// - Panics if the string is not valid JSON, with the parser's message
+    pub fn parse_json(&self) -> Value {
+        self.parse_embedded_json().unwrap_or_else(|error| self.panic_with(error))
+    }
+
+    /// Creates an assertion over the JSON document held by the string.
+    ///
+    /// The new assertion's path is `<path> (parsed JSON)`, and its value is
+    /// the root of the embedded document, so assertions such as `property` or
+    /// `nth` can descend into it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::{JsonTest, PropertyAssertions};
+    /// # use serde_json::json;
+    /// # let data = json!({"metadata": "{\"source\": \"import\", \"rows\": 120}"});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.metadata")
+    ///     .embedded_json()
+    ///     .has_property_value("source", json!("import"))
+    ///     .property("rows")
+    ///     .is_greater_than(0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a string
+    /// - Panics if the string is not valid JSON, with the parser's message
+    pub fn embedded_json(&mut self) -> JsonPathAssertion<'a> {
+        let parsed = match self.parse_embedded_json() {
+            Ok(parsed) => Some(parsed),
+            Err(error) => {
+                self.report_error(error);
+                None
+            }
+        };
+
+        JsonPathAssertion {
+            path_str: format!("{} (parsed JSON)", self.path_str),
+            current_values: parsed.map(Cow::Owned).into_iter().collect(),
+            test: self.test,
+        }
+    }
+
+    /// Returns copies of every matched value, in document order.
+    ///
+    /// Like `extract`, this is an escape hatch; a path that matched nothing
+    /// returns an empty vector.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"users": [{"id": 7}, {"id": 9}]});
+    /// # let test = JsonTest::new(&data);
+    /// let ids = test.assert_path("$.users[*].id").extract_all();
+    /// assert_eq!(ids, vec![json!(7), json!(9)]);
+    /// ```
+    pub fn extract_all(&self) -> Vec<Value> {
+        self.iter_matches().cloned().collect()
+    }
+
+    /// Deserializes each element of the array into `T` and returns them.
+    ///
+    /// The typed records can then be checked with plain Rust assertions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// #[derive(serde::Deserialize)]
+    /// struct User {
+    ///     name: String,
+    ///     age: u32,
+    /// }
+    ///
+    /// # let data = json!({"users": [{"name": "John", "age": 30}, {"name": "Jane", "age": 25}]});
+    /// # let test = JsonTest::new(&data);
+    /// let users: Vec<User> = test.assert_path("$.users").as_typed_vec();
+    /// assert_eq!(users[1].name, "Jane");
+    /// assert!(users.iter().all(|u| u.age >= 18));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not an array
+    /// - Panics on the first element that cannot be deserialized into `T`
+    pub fn as_typed_vec<T: DeserializeOwned>(&self) -> Vec<T> {
+        let arr = match self.first_value() {
+            Some(Value::Array(arr)) => arr,
+            Some(v) => self.panic_with(self.failure(format!("Expected array at {}, got {:?}", self.path_str, v))),
+            None => self.no_value(),
+        };
+
+        arr.iter()
+            .enumerate()
+            .map(|(index, element)| {
+                T::deserialize(element).unwrap_or_else(|e| {
+                    self.panic_with(self.failure(format!(
+                        "Failed to deserialize element {} of {} into {}: {}",
+                        index, self.path_str, std::any::type_name::<T>(), e
+                    )))
+                })
+            })
+            .collect()
+    }
+
+    /// Asserts that the value is an object and returns it for further testing.
+    ///
+    /// This method is primarily used internally by property assertions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"user": {"name": "John", "age": 30}});
+    /// # let test = JsonTest::new(&data);
+    /// let obj = test.assert_path("$.user")
+    ///     .assert_object();
+    /// assert!(obj.contains_key("name"));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not an object
+    pub fn assert_object(&self) -> Map<String, Value> {
+        match self.object_or_error() {
+            Ok(obj) => obj,
+            Err(error) => self.panic_with(error),
+        }
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn object_or_error(&self) -> Result<Map<String, Value>, JsonPathError> {
+        match self.current_values.as_slice() {
+            [Cow::Borrowed(Value::Object(obj))] => Ok(obj.clone()),
+            [Cow::Owned(Value::Object(obj))] => Ok(obj.clone()),
+            _ => Err(self.failure(format!(
+                "Expected object at {}, got: {:?}",
+                self.path_str, self.current_values
+            ))),
+        }
+    }
+
+    /// Creates an assertion over the values of the object, collected into an array.
+    ///
+    /// The new assertion's path is reported as `<path>(values)`, and every array
+    /// assertion can be used on it. Values keep the object's key order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"config": {"host": "localhost", "user": "admin"}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.config")
+    ///     .object_values()
+    ///     .has_length(2)
+    ///     .contains(&json!("admin"));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not an object
+    pub fn object_values(&mut self) -> JsonPathAssertion<'a> {
+        let values = self.assert_object().into_iter().map(|(_, v)| v).collect();
+        JsonPathAssertion {
+            path_str: format!("{}(values)", self.path_str),
+            current_values: vec![Cow::Owned(Value::Array(values))],
+            test: self.test,
+        }
+    }
+
+    /// Creates an assertion over the keys of the object, collected into an array of strings.
+    ///
+    /// The new assertion's path is reported as `<path>(keys)`, and every array
+    /// assertion can be used on it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"user": {"name": "John", "email": "john@example.com"}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.user")
+    ///     .object_keys_as_array()
+    ///     .has_length(2)
+    ///     .contains(&json!("name"));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not an object
+    pub fn object_keys_as_array(&mut self) -> JsonPathAssertion<'a> {
+        let keys = self.assert_object().into_iter().map(|(k, _)| Value::String(k)).collect();
+        JsonPathAssertion {
+            path_str: format!("{}(keys)", self.path_str),
+            current_values: vec![Cow::Owned(Value::Array(keys))],
+            test: self.test,
+        }
+    }
+
+    /// Asserts that the value is an object whose properties are all scalars.
+    ///
+    /// Strings, numbers, booleans and nulls are allowed; nested objects and
+    /// arrays are not.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"settings": {"theme": "dark", "font_size": 14, "beta": false}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.settings")
+    ///     .is_flat_object();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not an object
+    /// - Panics if any property is an object or an array
+    pub fn is_flat_object(&mut self) -> &mut Self {
+        let obj = ok_or_fail!(self, self.object_or_error());
+        if let Some((key, value)) = obj.iter().find(|(_, v)| v.is_object() || v.is_array()) {
+            fail!(
+                self,
+                "Property '{}' at {} is a nested {}, expected flat object",
+                key, self.path_str, type_name(value)
+            );
+        }
+        self
+    }
+
+    /// Asserts that a property named `key` exists somewhere in the subtree, at any depth.
+    ///
+    /// Objects nested inside arrays are searched as well.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"response": {"data": {"items": [{"meta": {"etag": "abc"}}]}}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.response")
+    ///     .deep_has_key("etag");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if no object in the subtree has the key
+    pub fn deep_has_key(&mut self, key: &str) -> &mut Self {
+        match self.first_value() {
+            Some(value) if first_key_occurrence(value, key, "").is_some() => self,
+            Some(_) => fail!(self, "Key '{}' not found anywhere under {}", key, self.path_str),
+            None => fail_with!(self, self.missing_error(None)),
+        }
+    }
+
+    /// Asserts that no property named `key` exists anywhere in the subtree.
+    ///
+    /// This is a guard against leaking fields such as `password` or `secret`
+    /// in nested responses.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"user": {"name": "John", "auth": {"provider": "github"}}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.user")
+    ///     .deep_has_no_key("password");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if any object in the subtree has the key, naming the first location found
+    pub fn deep_has_no_key(&mut self, key: &str) -> &mut Self {
+        let value = match self.first_value() {
+            Some(value) => value,
+            None => fail_with!(self, self.missing_error(None)),
+        };
+        if let Some(subpath) = first_key_occurrence(value, key, "") {
+            fail!(self, "Forbidden key '{}' found at {}{}", key, self.path_str, subpath);
+        }
+        self
+    }
+
+    /// Asserts that the value equals the value at another path.
+    ///
+    /// The other path is resolved from the document root, so related fields
+    /// such as an order total and a summary total can be compared without
+    /// hardcoding either value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// let data = json!({
+    ///     "order": {"total": 42.5},
+    ///     "summary": {"grand_total": 42.5}
+    /// });
+    ///
+    /// let test = JsonTest::new(&data);
+    /// test.assert_path("$.order.total")
+    ///     .equals_path("$.summary.grand_total");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at either path
+    /// - Panics if the values differ
+    /// - Panics if called on an assertion without test context
+    pub fn equals_path(&mut self, other_path: &str) -> &mut Self {
+        let value = match self.first_value() {
+            Some(value) => value,
+            None => fail_with!(self, self.missing_error(None)),
+        };
+        let other = match self.resolve_from_root(other_path).first() {
+            Some(other) => other.clone(),
+            None => fail!(self, "No value found at {}", other_path),
+        };
+
+        if value != other.as_ref() {
+            fail!(
+                self,
+                "Value at {} does not equal value at {}\n{}: {}\n{}: {}",
+                self.path_str, other_path, self.path_str, value, other_path, other
+            );
+        }
+        self
+    }
+
+    /// Asserts that the object is a recursive subset of the object at another path.
+    ///
+    /// Every key of the current object must exist in the other object with an
+    /// equal value; nested objects are compared the same way, while all other
+    /// values must match exactly. The other path is resolved from the document root.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// let data = json!({
+    ///     "defaults": {"retries": 3, "http": {"timeout": 30}},
+    ///     "effective": {"retries": 3, "debug": true, "http": {"timeout": 30, "proxy": null}}
+    /// });
+    ///
+    /// let test = JsonTest::new(&data);
+    /// test.assert_path("$.defaults")
+    ///     .is_subset_of_path("$.effective");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if either value is missing or is not an object
+    /// - Panics if a key is missing from the other object or its value differs
+    /// - Panics if called on an assertion without test context
+    pub fn is_subset_of_path(&mut self, other_path: &str) -> &mut Self {
+        let subset = Value::Object(ok_or_fail!(self, self.object_or_error()));
+        let superset = match self.resolve_from_root(other_path).first().map(Cow::as_ref) {
+            Some(value @ Value::Object(_)) => value.clone(),
+            Some(v) => fail!(self, "Expected object at {}, got {:?}", other_path, v),
+            None => fail!(self, "No value found at {}", other_path),
+        };
+
+        match first_subset_violation(&subset, &superset, "") {
+            Some((subpath, expected, None)) => fail!(
+                self,
+                "Object at {} is not a subset of {}: {}{} is missing\nExpected: {}",
+                self.path_str, other_path, other_path, subpath, expected
+            ),
+            Some((subpath, expected, Some(actual))) => fail!(
+                self,
+                "Object at {} is not a subset of {}: value mismatch at {}{}\nExpected: {}\nActual: {}",
+                self.path_str, other_path, other_path, subpath, expected, actual
+            ),
+            None => self,
+        }
+    }
+
+    /// Asserts that the object has the same set of keys as the object at another path.
+    ///
+    /// Values are not compared. The other path is resolved from the document root,
+    /// so key-aligned structures such as parallel `labels` and `values` maps can be
+    /// checked in one call.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// let data = json!({
+    ///     "labels": {"cpu": "CPU usage", "mem": "Memory"},
+    ///     "values": {"mem": 0.42, "cpu": 0.9}
+    /// });
+    ///
+    /// let test = JsonTest::new(&data);
+    /// test.assert_path("$.labels")
+    ///     .keys_match_path("$.values");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if either value is missing or is not an object
+    /// - Panics if the key sets differ, listing the keys found on only one side
+    /// - Panics if called on an assertion without test context
+    pub fn keys_match_path(&mut self, other_path: &str) -> &mut Self {
+        let obj = ok_or_fail!(self, self.object_or_error());
+        let other = match self.resolve_from_root(other_path).first().map(Cow::as_ref) {
+            Some(Value::Object(other)) => other.clone(),
+            Some(v) => fail!(self, "Expected object at {}, got {:?}", other_path, v),
+            None => fail!(self, "No value found at {}", other_path),
+        };
+
+        let only_here: Vec<&str> = obj.keys()
+            .filter(|k| !other.contains_key(*k))
+            .map(|k| k.as_str())
+            .collect();
+        let only_there: Vec<&str> = other.keys()
+            .filter(|k| !obj.contains_key(*k))
+            .map(|k| k.as_str())
+            .collect();
+
+        if !only_here.is_empty() || !only_there.is_empty() {
+            fail!(
+                self,
+                "Keys at {} do not match keys at {}\nOnly in {}: {:?}\nOnly in {}: {:?}",
+                self.path_str, other_path, self.path_str, only_here, other_path, only_there
+            );
+        }
+        self
+    }
+
+    /// Creates a new assertion for a different path while maintaining the test context.
+    ///
+    /// This method enables chaining assertions across different paths.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::{JsonTest, PropertyAssertions};
+    /// # use serde_json::json;
+    /// # let data = json!({
+    /// #     "user": {"name": "John"},
+    /// #     "settings": {"theme": "dark"}
+    /// # });
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.user")
+    ///     .has_property("name")
+    ///     .assert_path("$.settings")
+    ///     .has_property("theme");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if called on an assertion without test context
+    pub fn assert_path(&mut self, path: &str) -> JsonPathAssertion<'a> {
+        match self.test {
+            Some(test) => test.assert_path(path),
+            None => panic!("Cannot chain assertions without JsonTest context"),
+        }
+    }
+
+    /// Runs assertions on another path and then continues with this one.
+    ///
+    /// The closure receives an assertion for `path`, resolved from the document
+    /// root. Afterwards the original assertion is returned, so a chain can dip
+    /// into a related path and come back.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::{JsonTest, PropertyAssertions};
+    /// # use serde_json::json;
+    /// # let data = json!({
+    /// #     "order": {"status": "shipped", "tracking": "1Z999"},
+    /// #     "shipment": {"carrier": "UPS"}
+    /// # });
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.order")
+    ///     .has_property_value("status", json!("shipped"))
+    ///     .and_also("$.shipment.carrier", |carrier| {
+    ///         carrier.exists().equals(json!("UPS"));
+    ///     })
+    ///     .has_property("tracking");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if called on an assertion without test context
+    /// - Panics if any assertion inside the closure fails
+    pub fn and_also<F>(&mut self, path: &str, f: F) -> &mut Self
+    where
+        F: for<'b> FnOnce(&'b mut JsonPathAssertion<'b>),
+    {
+        let test = match self.test {
+            Some(test) => test,
+            None => fail!(self, "Cannot chain assertions without JsonTest context"),
+        };
+        let mut other = test.assert_path(path);
+        f(&mut other);
+        self
+    }
+
+    /// Evaluates another path against the root document of the test.
+    fn resolve_from_root(&self, path: &str) -> Vec<Cow<'a, Value>> {
+        match self.test {
+            Some(test) => test.resolve(path),
+            None => panic!("Cannot resolve {} without JsonTest context", path),
+        }
+    }
+}
+
+/// Helpers that read the current value as a particular kind of string or
+/// number, returning the failure for the calling assertion to report.
+#[allow(clippy::result_large_err)]
+impl<'a> JsonPathAssertion<'a> {
+    fn parse_boolean_string(&self, ignore_case: bool) -> Result<bool, JsonPathError> {
+        let s = match self.first_value() {
+            Some(Value::String(s)) => s,
+            Some(v) => return Err(self.failure(format!("Expected string at {}, got {:?}", self.path_str, v))),
+            None => return Err(self.missing_error(None)),
+        };
+        let matches = |word: &str| if ignore_case { s.eq_ignore_ascii_case(word) } else { s == word };
+
+        if matches("true") {
+            Ok(true)
+        } else if matches("false") {
+            Ok(false)
+        } else {
+            Err(self.failure(format!("String at {} is not a boolean string\nActual: {}", self.path_str, s)))
+        }
+    }
+
+    fn parse_number(&self) -> Result<(f64, &str), JsonPathError> {
+        match self.first_value() {
+            Some(Value::String(s)) => match s.parse::<f64>() {
+                Ok(parsed) if parsed.is_finite() => Ok((parsed, s)),
+                _ => Err(self.failure(format!(
+                    "String at {} ('{}') is not a valid number",
+                    self.path_str, s
+                ))),
+            },
+            Some(v) => Err(self.failure(format!("Expected string at {}, got {:?}", self.path_str, v))),
+            None => Err(self.missing_error(None)),
+        }
+    }
+
+    fn parse_date(&self, kind: &str) -> Result<((u32, u32, u32), &str), JsonPathError> {
+        match self.first_value() {
+            Some(Value::String(s)) => match parse_date(s) {
+                Some(date) => Ok((date, s)),
+                None => Err(self.failure(format!(
+                    "String at {} is not {} (YYYY-MM-DD)\nActual: {}",
+                    self.path_str, kind, s
+                ))),
+            },
+            Some(v) => Err(self.failure(format!("Expected string at {}, got {:?}", self.path_str, v))),
+            None => Err(self.missing_error(None)),
+        }
+    }
+
+    fn parse_datetime(&self) -> Result<((i64, u32), &str), JsonPathError> {
+        match self.first_value() {
+            Some(Value::String(s)) => match parse_rfc3339(s) {
+                Some(instant) => Ok((instant, s)),
+                None => Err(self.failure(format!(
+                    "String at {} is not an RFC 3339 date-time (YYYY-MM-DDTHH:MM:SS[.fff](Z|±HH:MM))\nActual: {}",
+                    self.path_str, s
+                ))),
+            },
+            Some(v) => Err(self.failure(format!("Expected string at {}, got {:?}", self.path_str, v))),
+            None => Err(self.missing_error(None)),
+        }
+    }
+
+    fn parse_embedded_json(&self) -> Result<Value, JsonPathError> {
+        match self.first_value() {
+            Some(Value::String(s)) => serde_json::from_str(s).map_err(|e| self.failure(format!(
+                "String at {} is not valid JSON: {}\nActual: {}",
+                self.path_str, e, s
+            ))),
+            Some(v) => Err(self.failure(format!("Expected string at {}, got {:?}", self.path_str, v))),
+            None => Err(self.missing_error(None)),
+        }
+    }
+}
+
+/// Returns true for a non-empty string in the standard or URL-safe base64
+/// alphabet, with optional `=` padding.
+fn is_base64(s: &str) -> bool {
+    let data = s.trim_end_matches('=');
+    let padding = s.len() - data.len();
+    let valid_chars = data
+        .bytes()
+        .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'/' | b'-' | b'_'));
+    let valid_length = if padding == 0 { data.len() % 4 != 1 } else { padding <= 2 && s.len().is_multiple_of(4) };
+
+    !data.is_empty() && valid_chars && valid_length
+}
+
+/// Parses a `YYYY-MM-DD` calendar date into `(year, month, day)`.
+///
+/// Returns `None` unless the month exists and the day exists in that month.
+fn parse_date(s: &str) -> Option<(u32, u32, u32)> {
+    let bytes = s.as_bytes();
+    if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+        return None;
+    }
+    let number = |range: std::ops::Range<usize>| -> Option<u32> {
+        let part = &s[range];
+        if part.bytes().all(|b| b.is_ascii_digit()) { part.parse().ok() } else { None }
+    };
+    let (year, month, day) = (number(0..4)?, number(5..7)?, number(8..10)?);
+
+    let leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap => 29,
+        2 => 28,
+        _ => return None,
+    };
+    (1..=days_in_month).contains(&day).then_some((year, month, day))
+}
+
+/// Parses an RFC 3339 date-time into Unix seconds and nanoseconds.
+///
+/// Fractions beyond nanosecond precision are truncated.
+fn parse_rfc3339(s: &str) -> Option<(i64, u32)> {
+    if !s.is_ascii() || s.len() < 20 {
+        return None;
+    }
+    let (year, month, day) = parse_date(&s[..10])?;
+    let bytes = s.as_bytes();
+    if !matches!(bytes[10], b'T' | b't') || bytes[13] != b':' || bytes[16] != b':' {
+        return None;
+    }
+    let number = |part: &str| -> Option<i64> {
+        if part.bytes().all(|b| b.is_ascii_digit()) { part.parse().ok() } else { None }
+    };
+    let (hour, minute, second) = (number(&s[11..13])?, number(&s[14..16])?, number(&s[17..19])?);
+    if hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+
+    let mut rest = &s[19..];
+    let mut nanos = 0;
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let len = fraction.bytes().take_while(u8::is_ascii_digit).count();
+        if len == 0 {
+            return None;
+        }
+        let digits = &fraction[..len.min(9)];
+        nanos = digits.parse::<u32>().ok()? * 10u32.pow(9 - digits.len() as u32);
+        rest = &fraction[len..];
+    }
+
+    let offset = match rest.as_bytes() {
+        [b'Z' | b'z'] => 0,
+        [sign @ (b'+' | b'-'), _, _, b':', _, _] => {
+            let (hours, minutes) = (number(&rest[1..3])?, number(&rest[4..6])?);
+            if hours > 23 || minutes > 59 {
+                return None;
+            }
+            let offset = hours * 3600 + minutes * 60;
+            if *sign == b'-' { -offset } else { offset }
+        }
+        _ => return None,
+    };
+
+    let days = days_from_civil(year as i64, month as i64, day as i64);
+    Some((days * 86_400 + hour * 3600 + minute * 60 + second - offset, nanos))
+}
+
+/// Number of days from 1970-01-01 to a proleptic Gregorian date.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Returns true if the number has no fractional part, whether written as `2` or `2.0`.
+fn is_whole_number(n: &Number) -> bool {
+    n.is_i64() || n.is_u64() || n.as_f64().is_some_and(|f| f.fract() == 0.0)
+}
+
+/// Searches `value` depth-first for an object property named `key` and returns
+/// the relative path of the first one found.
+fn first_key_occurrence(value: &Value, key: &str, at: &str) -> Option<String> {
+    match value {
+        Value::Object(obj) if obj.contains_key(key) => Some(format!("{}.{}", at, key)),
+        Value::Object(obj) => obj
+            .iter()
+            .find_map(|(k, v)| first_key_occurrence(v, key, &format!("{}.{}", at, k))),
+        Value::Array(arr) => arr
+            .iter()
+            .enumerate()
+            .find_map(|(i, v)| first_key_occurrence(v, key, &format!("{}[{}]", at, i))),
+        _ => None,
+    }
+}
+
+/// Finds the first place where `subset` is not contained in `superset`.
+///
+/// Returns the relative path of the offending value, the value from `subset` and
+/// the corresponding value from `superset`, if any.
+fn first_subset_violation<'v>(
+    subset: &'v Value,
+    superset: &'v Value,
+    at: &str,
+) -> Option<(String, &'v Value, Option<&'v Value>)> {
+    match (subset, superset) {
+        (Value::Object(sub), Value::Object(sup)) => sub.iter().find_map(|(key, value)| {
+            let at = format!("{}.{}", at, key);
+            match sup.get(key) {
+                Some(other) => first_subset_violation(value, other, &at),
+                None => Some((at, value, None)),
+            }
+        }),
+        _ if subset == superset => None,
+        _ => Some((at.to_string(), subset, Some(superset))),
+    }
+}
+
+/// Compares `actual` and `expected` as multisets, returning the expected
+/// values that are missing and the actual values that are unexpected.
+///
+/// Each actual value can account for only one expected value, so duplicates
+/// must appear the same number of times on both sides.
+fn multiset_difference<'v>(
+    actual: impl IntoIterator<Item = &'v Value>,
+    expected: &'v [Value],
+) -> (Vec<&'v Value>, Vec<&'v Value>) {
+    let mut unexpected: Vec<&Value> = actual.into_iter().collect();
+    let mut missing = Vec::new();
+    for value in expected {
+        match unexpected.iter().position(|actual| *actual == value) {
+            Some(index) => {
+                unexpected.swap_remove(index);
+            }
+            None => missing.push(value),
+        }
+    }
+    (missing, unexpected)
+}
+
+/// Finds the first place where `actual` differs from `expected`, treating
+/// numbers within `epsilon` of each other as equal.
+///
+/// Returns the relative path of the difference with the expected and actual
+/// values found there; `None` on either side means the value is missing.
+fn first_approx_difference<'v>(
+    actual: &'v Value,
+    expected: &'v Value,
+    epsilon: f64,
+    at: &str,
+) -> Option<(String, Option<&'v Value>, Option<&'v Value>)> {
+    match (actual, expected) {
+        (Value::Number(a), Value::Number(e)) => match (a.as_f64(), e.as_f64()) {
+            (Some(a), Some(e)) if (a - e).abs() <= epsilon => None,
+            _ => Some((at.to_string(), Some(expected), Some(actual))),
+        },
+        (Value::Object(a), Value::Object(e)) => e
+            .iter()
+            .find_map(|(key, e)| {
+                let at = format!("{}.{}", at, key);
+                match a.get(key) {
+                    Some(a) => first_approx_difference(a, e, epsilon, &at),
+                    None => Some((at, Some(e), None)),
+                }
+            })
+            .or_else(|| {
+                a.iter()
+                    .find(|(key, _)| !e.contains_key(*key))
+                    .map(|(key, a)| (format!("{}.{}", at, key), None, Some(a)))
+            }),
+        (Value::Array(a), Value::Array(e)) if a.len() == e.len() => a
+            .iter()
+            .zip(e)
+            .enumerate()
+            .find_map(|(i, (a, e))| first_approx_difference(a, e, epsilon, &format!("{}[{}]", at, i))),
+        _ if actual == expected => None,
+        _ => Some((at.to_string(), Some(expected), Some(actual))),
+    }
+}
+
+/// Lists every difference between `actual` and `expected`, one line per
+/// differing path, with paths built on `at`.
+pub(crate) fn json_diff(actual: &Value, expected: &Value, at: &str) -> Vec<String> {
+    let mut lines = Vec::new();
+    collect_diff(actual, expected, at, &mut lines);
+    lines
+}
+
+fn collect_diff(actual: &Value, expected: &Value, at: &str, lines: &mut Vec<String>) {
+    match (actual, expected) {
+        (Value::Object(a), Value::Object(e)) => {
+            for (key, e) in e {
+                let at = format!("{}.{}", at, key);
+                match a.get(key) {
+                    Some(a) => collect_diff(a, e, &at, lines),
+                    None => lines.push(format!("{}: missing in actual, expected {}", at, e)),
+                }
+            }
+            for (key, a) in a.iter().filter(|(key, _)| !e.contains_key(*key)) {
+                lines.push(format!("{}.{}: unexpected in actual, found {}", at, key, a));
+            }
+        }
+        (Value::Array(a), Value::Array(e)) => {
+            for (i, (a, e)) in a.iter().zip(e).enumerate() {
+                collect_diff(a, e, &format!("{}[{}]", at, i), lines);
+            }
+            for (i, e) in e.iter().enumerate().skip(a.len()) {
+                lines.push(format!("{}[{}]: missing in actual, expected {}", at, i, e));
+            }
+            for (i, a) in a.iter().enumerate().skip(e.len()) {
+                lines.push(format!("{}[{}]: unexpected in actual, found {}", at, i, a));
+            }
+        }
+        _ if actual == expected => {}
+        _ if type_name(actual) != type_name(expected) => lines.push(format!(
+            "{}: expected {} {}, actual {} {}",
+            at, type_name(expected), expected, type_name(actual), actual
+        )),
+        _ => lines.push(format!("{}: expected {}, actual {}", at, expected, actual)),
+    }
+}
+
+/// Walks a simple path of keys and indices from `root` and returns the longest
+/// prefix of `path` that exists, together with the node it selects.
+///
+/// Returns `None` when the path fully resolves or uses selectors such as
+/// wildcards, filters or recursive descent, which have no single route to follow.
+fn deepest_existing_prefix<'v>(root: &'v Value, path: &str) -> Option<(String, &'v Value)> {
+    let mut rest = path.strip_prefix('$')?;
+    let mut node = root;
+
+    while !rest.is_empty() {
+        let (child, consumed) = if let Some(after) = rest.strip_prefix("['") {
+            let end = after.find("']")?;
+            (node.get(&after[..end]), end + 4)
+        } else if let Some(after) = rest.strip_prefix("[\"") {
+            let end = after.find("\"]")?;
+            (node.get(&after[..end]), end + 4)
+        } else if let Some(after) = rest.strip_prefix('[') {
+            let end = after.find(']')?;
+            (node.get(after[..end].parse::<usize>().ok()?), end + 2)
+        } else if let Some(after) = rest.strip_prefix('.') {
+            let end = after.find(['.', '[']).unwrap_or(after.len());
+            let key = &after[..end];
+            if key.is_empty() || key.contains(['*', '(', '@']) {
+                return None;
+            }
+            (node.get(key), end + 1)
+        } else {
+            return None;
+        };
+
+        match child {
+            Some(child) => {
+                node = child;
+                rest = &rest[consumed..];
+            }
+            None => break,
+        }
+    }
+
+    if rest.is_empty() {
+        return None;
+    }
+    Some((path[..path.len() - rest.len()].to_string(), node))
+}
+
+/// The text a failure panics with: the bare message for assertion failures.
+fn failure_message(error: JsonPathError) -> String {
+    match error {
+        JsonPathError::AssertionFailed { message, .. } => message,
+        other => other.to_string(),
+    }
+}
+
+/// Explains why a filter such as `[?(@.total > 100)]` may have matched nothing.
+///
+/// Only the first filter in the path is inspected, and only simple comparisons
+/// of a direct field against a string, number or boolean literal. Returns a hint
+/// when some elements of the filtered arrays hold that field with another type.
+fn filter_type_hint(root: &Value, path: &str) -> Option<String> {
+    static FILTER: OnceLock<regex::Regex> = OnceLock::new();
+    let re = FILTER.get_or_init(|| {
+        regex::Regex::new(r#"^(\$.*?)\[\?\(?\s*@\.(\w+)\s*(?:==|!=|>=|<=|>|<)\s*(.+?)\s*\)?\]"#)
+            .expect("filter pattern is valid")
+    });
+    let caps = re.captures(path)?;
+    let field = &caps[2];
+    let literal = &caps[3];
+    let literal_type = if literal.starts_with(['\'', '"']) {
+        "string"
+    } else if literal == "true" || literal == "false" {
+        "boolean"
+    } else if literal.parse::<f64>().is_ok() {
+        "number"
+    } else {
+        return None;
+    };
+
+    let arrays = resolve_path(root, &caps[1]);
+    let elements: Vec<&Value> = arrays.iter().filter_map(|v| v.as_array()).flatten().collect();
+    let actual_types: Vec<String> = elements
+        .iter()
+        .filter_map(|element| element.get(field))
+        .map(type_name)
+        .collect();
+    let mismatched = actual_types.iter().find(|t| *t != literal_type)?;
+    let count = actual_types.iter().filter(|t| *t == mismatched).count();
+    let article = if mismatched.starts_with(['a', 'o']) { "an" } else { "a" };
+
+    Some(format!(
+        "Hint: field '{}' is {} {} in {} of {} elements; filter comparisons may not apply",
+        field, article, mismatched, count, elements.len()
+    ))
+}
+
+/// Describes a node reached by `deepest_existing_prefix` for error messages.
+fn describe_node(node: &Value) -> String {
+    match node {
+        Value::Object(obj) if obj.is_empty() => "which has no keys".to_string(),
+        Value::Object(obj) => format!(
+            "which has keys: {}",
+            obj.keys().map(|k| k.as_str()).collect::<Vec<_>>().join(", ")
+        ),
+        Value::Array(arr) => format!("which is an array of length {}", arr.len()),
+        other => format!("which is {}", other),
+    }
+}
+
+/// A value selected by a JSONPath expression.
+#[derive(Debug)]
+pub(crate) struct PathMatch<'v> {
+    /// The matched value, borrowed when it is a node of the document.
+    pub(crate) value: Cow<'v, Value>,
+    /// The normalized path of a matched node, such as `$.['users'][0]`, or
+    /// `None` for a value computed by the expression, such as `length()`.
+    pub(crate) normalized: Option<String>,
+}
+
+/// Evaluates a JSONPath expression against `json` and records every match.
+///
+/// Wildcards, unions, slices, filters and recursive descent can all select
+/// several nodes; each one becomes a separate entry in the returned vector.
+///
+/// # Panics
+///
+/// Panics if the path starts with neither `$` nor `@`, suggesting a rooted
+/// equivalent, or if it is not a valid JSONPath expression. A path starting
+/// with `@` is relative to the current node, which here is the root of `json`.
+pub(crate) fn find_matches<'v>(json: &'v Value, path: &str) -> Vec<PathMatch<'v>> {
+    if !path.starts_with(['$', '@']) {
+        panic!("JSONPath '{}' must start with '$'. Did you mean '{}'?", path, rooted(path));
+    }
+    let parsed_path = JsonPath::<Value>::from_str(path)
+        .unwrap_or_else(|e| panic!("Invalid JSONPath expression: {}", e));
+
+    parsed_path
+        .find_slice(json)
+        .into_iter()
+        .filter_map(|found| match found {
+            // The matched value borrows from `parsed_path` as well as `json`,
+            // so the node is located again to borrow it from `json` alone.
+            JsonPathValue::Slice(value, normalized) => Some(match locate(json, &normalized) {
+                Some(node) if std::ptr::eq(node, value) => PathMatch {
+                    value: Cow::Borrowed(node),
+                    normalized: Some(normalized),
+                },
+                // Keys that the normalized path cannot represent unambiguously
+                // fall back to a copy of the matched value.
+                _ => PathMatch { value: Cow::Owned(value.clone()), normalized: None },
+            }),
+            JsonPathValue::NewValue(value) => Some(PathMatch { value: Cow::Owned(value), normalized: None }),
+            JsonPathValue::NoValue => None,
+        })
+        .collect()
+}
+
+/// Suggests a rooted version of a path that does not start with `$`.
+fn rooted(path: &str) -> String {
+    if path.starts_with(['.', '[']) {
+        format!("${}", path)
+    } else {
+        format!("$.{}", path)
+    }
+}
+
+/// Evaluates a JSONPath expression against `json` and returns every matched value.
+pub(crate) fn resolve_path<'v>(json: &'v Value, path: &str) -> Vec<Cow<'v, Value>> {
+    find_matches(json, path).into_iter().map(|found| found.value).collect()
+}
+
+/// One step of a normalized path: an object key or an array index.
+enum Step<'p> {
+    Key(&'p str),
+    Index(usize),
+}
+
+/// Splits a normalized path such as `$.['users'][0].['name']` into steps,
+/// following it from the root of `json`, and returns the node it leads to.
+fn normalized_steps<'v, 'p>(json: &'v Value, normalized: &'p str) -> Option<(Vec<Step<'p>>, &'v Value)> {
+    let mut current = json;
+    let mut steps = Vec::new();
+    let mut rest = normalized.strip_prefix('$')?;
+
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix(".['") {
+            // Keys are not escaped, so a key may itself contain `']`. Try the
+            // longest candidate first, looking each one up in the object.
+            let obj = current.as_object()?;
+            let (end, value) = after
+                .rmatch_indices("']")
+                .find_map(|(end, _)| obj.get(&after[..end]).map(|value| (end, value)))?;
+            current = value;
+            steps.push(Step::Key(&after[..end]));
+            rest = &after[end + 2..];
+        } else {
+            let after = rest.strip_prefix('[')?;
+            let end = after.find(']')?;
+            let index = after[..end].parse::<usize>().ok()?;
+            current = current.as_array()?.get(index)?;
+            steps.push(Step::Index(index));
+            rest = &after[end + 1..];
+        }
+    }
+    Some((steps, current))
+}
+
+/// Follows a normalized path from the root of `json`.
+pub(crate) fn locate<'v>(json: &'v Value, normalized: &str) -> Option<&'v Value> {
+    normalized_steps(json, normalized).map(|(_, node)| node)
+}
+
+/// Removes the node at a normalized path from `json`.
+///
+/// Object properties are removed; array elements are replaced with `null` so
+/// that the indices of their siblings stay the same.
+fn remove_node(json: &mut Value, normalized: &str) {
+    let steps = match normalized_steps(json, normalized) {
+        Some((steps, _)) => steps,
+        None => return,
+    };
+    let (last, parents) = match steps.split_last() {
+        Some(split) => split,
+        None => {
+            *json = Value::Null;
+            return;
+        }
+    };
+
+    let parent = parents.iter().try_fold(json, |node, step| match step {
+        Step::Key(key) => node.get_mut(*key),
+        Step::Index(index) => node.get_mut(*index),
+    });
+    match (parent, last) {
+        (Some(Value::Object(obj)), Step::Key(key)) => {
+            obj.remove(*key);
+        }
+        (Some(Value::Array(arr)), Step::Index(index)) => arr[*index] = Value::Null,
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_field_type_consistent_across() {
+        let json = json!({"users": [{"id": 1, "name": "a"}, {"id": 2, "name": "b"}]});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.users");
+        assertion
+            .field_type_consistent_across("id")
+            .field_type_consistent_across("name");
+    }
+
+    #[test]
+    #[should_panic(expected = "Field 'id' has inconsistent types across array at $.users: number at index 0, string at index 2")]
+    fn test_field_type_inconsistent() {
+        let json = json!({"users": [{"id": 1}, {"id": 2}, {"id": "3"}]});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.users");
+        assertion.field_type_consistent_across("id");
+    }
+
+    #[test]
+    #[should_panic(expected = "number at index 0, missing at index 1")]
+    fn test_field_type_missing_key() {
+        let json = json!({"users": [{"id": 1}, {"name": "b"}]});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.users");
+        assertion.field_type_consistent_across("id");
+    }
+
+    #[test]
+    fn test_union_selects_all_values() {
+        let json = json!({"user": {"name": "John", "email": "john@example.com", "age": 30}});
+        let assertion = JsonPathAssertion::new_for_test(&json, "$.user['name','email']");
+        assert_eq!(assertion.current_values, vec![Cow::Owned::<Value>(json!("John")), Cow::Owned(json!("john@example.com"))]);
+    }
+
+    #[test]
+    fn test_matches_borrow_document_nodes() {
+        let items: Vec<Value> = (0..1000).map(|i| json!({"id": i, "tags": ["a", "b"]})).collect();
+        let json = json!({"data": {"items": items}});
+        let assertion = JsonPathAssertion::new_for_test(&json, "$.data.items[?(@.id >= 500)]");
+
+        assert_eq!(assertion.current_values.len(), 500);
+        for (i, value) in assertion.current_values.iter().enumerate() {
+            match value {
+                Cow::Borrowed(node) => assert!(std::ptr::eq(*node, &json["data"]["items"][500 + i])),
+                Cow::Owned(_) => panic!("Match {} was cloned", i),
+            }
+        }
+    }
+
+    #[test]
+    fn test_locate_unusual_keys() {
+        let json = json!({"it's": 1, "a.b": [true], "a": {"b": 2}});
+        let assertion = JsonPathAssertion::new_for_test(&json, "$..*");
+        assert!(assertion.current_values.iter().all(|v| matches!(v, Cow::Borrowed(_))));
+        assert_eq!(locate(&json, "$.['a.b'][0]"), Some(&json!(true)));
+        assert_eq!(locate(&json, "$.['a'].['b']"), Some(&json!(2)));
+    }
+
+    #[test]
+    fn test_computed_values_are_owned() {
+        let json = json!({"items": [1, 2, 3]});
+        let assertion = JsonPathAssertion::new_for_test(&json, "$.items.length()");
+        assert!(matches!(assertion.current_values.as_slice(), [Cow::Owned(Value::Number(n))] if n.as_u64() == Some(3)));
+    }
+
+    #[test]
+    fn test_values_equal_ignores_order() {
+        let json = json!({"users": [{"name": "a"}, {"name": "b"}, {"name": "a"}]});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.users[*].name");
+        assertion.values_equal(&[json!("b"), json!("a"), json!("a")]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Missing: [String(\"c\")]\nUnexpected: [String(\"a\")]")]
+    fn test_values_equal_mismatch() {
+        let json = json!({"users": [{"name": "a"}, {"name": "b"}]});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.users[*].name");
+        assertion.values_equal(&[json!("b"), json!("c")]);
+    }
+
+    #[test]
+    fn test_has_no_newlines() {
+        let json = json!({"message": "all on one line"});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.message");
+        assertion.has_no_newlines().is_single_line();
+    }
+
+    #[test]
+    #[should_panic(expected = "String at $.message contains a newline at byte 5\nActual: \"hello\\r\\nworld\"")]
+    fn test_has_no_newlines_carriage_return() {
+        let json = json!({"message": "hello\r\nworld"});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.message");
+        assertion.has_no_newlines();
+    }
+
+    #[test]
+    #[should_panic(expected = "JSONPath 'user.name' must start with '$'. Did you mean '$.user.name'?")]
+    fn test_unrooted_path() {
+        let json = json!({"user": {"name": "John"}});
+        let test = JsonTest::new(&json);
+        test.assert_path("user.name");
+    }
+
+    #[test]
+    fn test_relative_paths_accepted() {
+        let json = json!({"user": {"name": "John", "id": 1}, "owner": {"name": "John"}});
+        let test = JsonTest::new(&json);
+        test.assert_path("@.user.name").equals(json!("John"));
+        test.assert_path("@['user']['id']").equals(json!(1));
+        test.assert_path("$.user.name").equals_path("@.owner.name");
+        test.assert_path("$.user").equals_ignoring(json!({"name": "John"}), &["@.id"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "JSONPath 'summary.total' must start with '$'. Did you mean '$.summary.total'?")]
+    fn test_unrooted_path_from_root() {
+        let json = json!({"order": {"total": 1}, "summary": {"total": 1}});
+        let test = JsonTest::new(&json);
+        test.assert_path("$.order.total").equals_path("summary.total");
+    }
+
+    #[test]
+    #[should_panic(expected = "JSONPath '[0].id' must start with '$'. Did you mean '$[0].id'?")]
+    fn test_unrooted_ignore_path() {
+        let json = json!([{"id": 1}]);
+        JsonPathAssertion::new_for_test(&json, "$").equals_ignoring(json!([{"id": 2}]), &["[0].id"]);
+    }
+
+    #[test]
+    fn test_has_no_replacement_chars() {
+        let json = json!({"title": "Crème brûlée 🍮"});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.title");
+        assertion.has_no_replacement_chars();
+    }
+
+    #[test]
+    #[should_panic(expected = "String at $.title contains replacement character(s) indicating decode errors")]
+    fn test_has_no_replacement_chars_fails() {
+        let decoded = String::from_utf8_lossy(b"caf\xe9").into_owned();
+        let json = json!({"title": decoded});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.title");
+        assertion.has_no_replacement_chars();
+    }
+
+    #[test]
+    fn test_index_equals() {
+        let json = json!({"steps": ["build", "test", "deploy"]});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.steps");
+        assertion
+            .index_equals(0, json!("build"))
+            .index_equals(2, json!("deploy"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Element 1 of $.steps mismatch\nExpected: \"lint\"\nActual: \"test\"")]
+    fn test_index_equals_mismatch() {
+        let json = json!({"steps": ["build", "test"]});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.steps");
+        assertion.index_equals(1, json!("lint"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Index 5 out of bounds for array at $.steps with length 2")]
+    fn test_index_equals_out_of_bounds() {
+        let json = json!({"steps": ["build", "test"]});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.steps");
+        assertion.index_equals(5, json!("build"));
+    }
+
+    #[test]
+    fn test_is_schema_type() {
+        let json = json!({"id": 42, "whole": 3.0, "score": 9.5, "name": "a", "none": null});
+        JsonPathAssertion::new_for_test(&json, "$.id").is_schema_type("integer");
+        JsonPathAssertion::new_for_test(&json, "$.id").is_schema_type("number");
+        JsonPathAssertion::new_for_test(&json, "$.whole").is_schema_type("integer");
+        JsonPathAssertion::new_for_test(&json, "$.score").is_schema_type("number");
+        JsonPathAssertion::new_for_test(&json, "$.name").is_schema_type("string");
+        JsonPathAssertion::new_for_test(&json, "$.none").is_schema_type("null");
+    }
+
+    #[test]
+    #[should_panic(expected = "Value at $.score is not schema type 'integer'\nActual type: number")]
+    fn test_is_schema_type_fractional_integer() {
+        let json = json!({"score": 9.5});
+        JsonPathAssertion::new_for_test(&json, "$.score").is_schema_type("integer");
+    }
+
+    #[test]
+    #[should_panic(expected = "Unknown JSON Schema type 'int'")]
+    fn test_is_schema_type_unknown() {
+        let json = json!({"id": 42});
+        JsonPathAssertion::new_for_test(&json, "$.id").is_schema_type("int");
+    }
+
+    #[test]
+    fn test_ratio_with_path_between() {
+        let json = json!({"success": 995, "total": 1000});
+        let test = JsonTest::new(&json);
+        test.assert_path("$.success")
+            .ratio_with_path_between("$.total", 0.99, 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Ratio $.success/$.total = 0.5 not in [0.99, 1]")]
+    fn test_ratio_with_path_out_of_range() {
+        let json = json!({"success": 50, "total": 100});
+        let test = JsonTest::new(&json);
+        test.assert_path("$.success")
+            .ratio_with_path_between("$.total", 0.99, 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot compute ratio $.success/$.total: denominator at $.total is zero")]
+    fn test_ratio_with_path_zero_denominator() {
+        let json = json!({"success": 0, "total": 0});
+        let test = JsonTest::new(&json);
+        test.assert_path("$.success")
+            .ratio_with_path_between("$.total", 0.0, 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Path $.user.setings.theme does not exist; deepest existing prefix is $.user (which has keys: name, settings)")]
+    fn test_exists_reports_deepest_prefix() {
+        let json = json!({"user": {"name": "John", "settings": {"theme": "dark"}}});
+        let test = JsonTest::new(&json);
+        test.assert_path("$.user.setings.theme").exists();
+    }
+
+    #[test]
+    fn test_deepest_existing_prefix() {
+        let json = json!({"users": [{"name": "John"}], "a b": {"c": 1}});
+        let prefix = |path| deepest_existing_prefix(&json, path).map(|(p, _)| p);
+
+        assert_eq!(prefix("$.users[0].email"), Some("$.users[0]".to_string()));
+        assert_eq!(prefix("$.users[3].name"), Some("$.users".to_string()));
+        assert_eq!(prefix("$['a b'].d"), Some("$['a b']".to_string()));
+        assert_eq!(prefix("$.missing"), Some("$".to_string()));
+        assert_eq!(prefix("$.users[0].name"), None);
+        assert_eq!(prefix("$.users[*].email"), None);
+        assert_eq!(prefix("$..email"), None);
+    }
+
+    #[test]
+    fn test_windows_satisfy() {
+        let json = json!({"values": [1, 2, 4, 8], "short": [1]});
+        JsonPathAssertion::new_for_test(&json, "$.values")
+            .windows_satisfy(2, |w| w[0].as_i64() < w[1].as_i64());
+        JsonPathAssertion::new_for_test(&json, "$.short")
+            .windows_satisfy(3, |_| false);
+    }
+
+    #[test]
+    #[should_panic(expected = "Window at index 1 of $.values failed predicate: [Number(3), Number(2)]")]
+    fn test_windows_satisfy_fails() {
+        let json = json!({"values": [1, 3, 2]});
+        JsonPathAssertion::new_for_test(&json, "$.values")
+            .windows_satisfy(2, |w| w[0].as_i64() < w[1].as_i64());
+    }
+
+    #[test]
+    fn test_is_flat_object() {
+        let json = json!({"settings": {"theme": "dark", "font_size": 14, "beta": false, "proxy": null}});
+        JsonPathAssertion::new_for_test(&json, "$.settings").is_flat_object();
+    }
+
+    #[test]
+    #[should_panic(expected = "Property 'retry' at $.settings is a nested object, expected flat object")]
+    fn test_is_flat_object_nested() {
+        let json = json!({"settings": {"theme": "dark", "retry": {"count": 3}}});
+        JsonPathAssertion::new_for_test(&json, "$.settings").is_flat_object();
+    }
+
+    #[test]
+    fn test_equals_approx() {
+        let json = json!({"summary": {"mean": 0.30000000000000004, "points": [0.1, 2], "unit": "s"}});
+        JsonPathAssertion::new_for_test(&json, "$.summary")
+            .equals_approx(json!({"mean": 0.3, "points": [0.1, 2.0], "unit": "s"}), 1e-9);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value mismatch at $.summary.points[1] (epsilon 0.001)\nExpected: 0.25\nActual: 0.2")]
+    fn test_equals_approx_number_out_of_tolerance() {
+        let json = json!({"summary": {"points": [0.1, 0.2]}});
+        JsonPathAssertion::new_for_test(&json, "$.summary")
+            .equals_approx(json!({"points": [0.1, 0.25]}), 1e-3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value mismatch at $.summary.extra (epsilon 0.001)\nExpected: (missing)\nActual: true")]
+    fn test_equals_approx_unexpected_key() {
+        let json = json!({"summary": {"mean": 0.3, "extra": true}});
+        JsonPathAssertion::new_for_test(&json, "$.summary")
+            .equals_approx(json!({"mean": 0.3}), 1e-3);
+    }
+
+    #[test]
+    fn test_object_values() {
+        let json = json!({"config": {"host": "localhost", "user": "admin"}});
+        JsonPathAssertion::new_for_test(&json, "$.config")
+            .object_values()
+            .equals(json!(["localhost", "admin"]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Array at $.config(values) does not contain expected value")]
+    fn test_object_values_path_in_message() {
+        let json = json!({"config": {"host": "localhost"}});
+        JsonPathAssertion::new_for_test(&json, "$.config")
+            .object_values()
+            .contains(&json!("admin"));
+    }
+
+    #[test]
+    fn test_object_keys_as_array() {
+        let json = json!({"user": {"name": "John", "email": "john@example.com"}});
+        JsonPathAssertion::new_for_test(&json, "$.user")
+            .object_keys_as_array()
+            .equals(json!(["email", "name"]));
+    }
+
+    #[test]
+    fn test_equals_ignoring() {
+        let json = json!({"order": {"id": "ord_8f2k", "items": [{"sku": "A1", "at": 1}, {"sku": "B2", "at": 2}]}});
+        JsonPathAssertion::new_for_test(&json, "$.order").equals_ignoring(
+            json!({"items": [{"sku": "A1", "at": 0}, {"sku": "B2"}]}),
+            &["$.id", "$.items[*].at"],
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Value mismatch at $.order.items[1].sku (ignoring $.id)\nExpected: \"C3\"\nActual: \"B2\"")]
+    fn test_equals_ignoring_reports_remaining_difference() {
+        let json = json!({"order": {"id": "ord_8f2k", "items": [{"sku": "A1"}, {"sku": "B2"}]}});
+        JsonPathAssertion::new_for_test(&json, "$.order").equals_ignoring(
+            json!({"id": "ord_x", "items": [{"sku": "A1"}, {"sku": "C3"}]}),
+            &["$.id"],
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Value mismatch at $.order (ignoring $.id)\nExpected: {\"qty\":1.0}\nActual: {\"qty\":1}")]
+    fn test_equals_ignoring_integer_and_float() {
+        let json = json!({"order": {"id": "ord_8f2k", "qty": 1}});
+        JsonPathAssertion::new_for_test(&json, "$.order").equals_ignoring(json!({"id": "ord_x", "qty": 1.0}), &["$.id"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value mismatch at $.order (ignoring $.id)\nExpected: {\"qty\":9007199254740992}\nActual: {\"qty\":9007199254740993}")]
+    fn test_equals_ignoring_large_integers() {
+        let json = json!({"order": {"id": "ord_8f2k", "qty": 9_007_199_254_740_993u64}});
+        JsonPathAssertion::new_for_test(&json, "$.order")
+            .equals_ignoring(json!({"id": "ord_x", "qty": 9_007_199_254_740_992u64}), &["$.id"]);
+    }
+
+    #[test]
+    fn test_remove_node() {
+        let mut json = json!({"a": {"b": 1, "c": 2}, "list": [1, 2, 3]});
+        remove_node(&mut json, "$.['a'].['b']");
+        remove_node(&mut json, "$.['list'][1]");
+        remove_node(&mut json, "$.['missing']");
+        assert_eq!(json, json!({"a": {"c": 2}, "list": [1, null, 3]}));
+    }
+
+    #[test]
+    fn test_is_sorted_by() {
+        let json = json!({"items": [{"n": {"v": 1}}, {"n": {"v": 1}}, {"n": {"v": 3}}]});
+        JsonPathAssertion::new_for_test(&json, "$.items")
+            .is_sorted_by(true, |item| item["n"]["v"].as_f64());
+        JsonPathAssertion::new_for_test(&json, "$.items")
+            .is_sorted_by(false, |item| item["n"]["v"].as_f64().map(|v| -v));
+    }
+
+    #[test]
+    #[should_panic(expected = "Array at $.items is not sorted in descending order: key 1 at index 0 is followed by 3 at index 1")]
+    fn test_is_sorted_by_out_of_order() {
+        let json = json!({"items": [1, 3, 2]});
+        JsonPathAssertion::new_for_test(&json, "$.items")
+            .is_sorted_by(false, |item| item.as_f64());
+    }
+
+    #[test]
+    #[should_panic(expected = "Could not extract sort key from element at index 1 of $.items: \"x\"")]
+    fn test_is_sorted_by_missing_key() {
+        let json = json!({"items": [1, "x"]});
+        JsonPathAssertion::new_for_test(&json, "$.items")
+            .is_sorted_by(true, |item| item.as_f64());
+    }
+
+    #[test]
+    fn test_lengths() {
+        let json = json!({"code": "héllo", "tags": ["a", "b"], "user": {"name": "John"}});
+        assert_eq!(JsonPathAssertion::new_for_test(&json, "$.tags").array_length(), 2);
+        assert_eq!(JsonPathAssertion::new_for_test(&json, "$.user").object_size(), 1);
+        JsonPathAssertion::new_for_test(&json, "$.code").length_equals(5);
+        JsonPathAssertion::new_for_test(&json, "$.tags").length_equals(2);
+        JsonPathAssertion::new_for_test(&json, "$.user").length_equals(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Object at $.user has wrong length\nExpected: 2\nActual: 1")]
+    fn test_length_equals_mismatch() {
+        let json = json!({"user": {"name": "John"}});
+        JsonPathAssertion::new_for_test(&json, "$.user").length_equals(2);
+    }
+
+    #[derive(Serialize)]
+    struct User {
+        name: String,
+        age: u32,
+    }
+
+    #[test]
+    fn test_equals_serialized() {
+        let json = json!({"user": {"name": "John", "age": 30}});
+        JsonPathAssertion::new_for_test(&json, "$.user")
+            .equals_serialized(&User { name: "John".to_string(), age: 30 });
+    }
+
+    #[test]
+    #[should_panic(expected = "Value mismatch at $.user (first difference at $.user.age)")]
+    fn test_equals_serialized_mismatch() {
+        let json = json!({"user": {"name": "John", "age": 31}});
+        JsonPathAssertion::new_for_test(&json, "$.user")
+            .equals_serialized(&User { name: "John".to_string(), age: 30 });
+    }
+
+    #[test]
+    fn test_iter_matches() {
+        let json = json!({"users": [{"age": 17}, {"age": 30}]});
+        let assertion = JsonPathAssertion::new_for_test(&json, "$.users[*].age");
+        let ages: Vec<&Value> = assertion.iter_matches().collect();
+        assert_eq!(ages, vec![&json!(17), &json!(30)]);
+    }
+
+    #[test]
+    fn test_epoch_near_reference() {
+        let reference = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let json = json!({"secs": 1_700_000_030, "millis": 1_699_999_999_500u64});
+        JsonPathAssertion::new_for_test(&json, "$.secs")
+            .is_epoch_seconds_near(reference, Duration::from_secs(30));
+        JsonPathAssertion::new_for_test(&json, "$.millis")
+            .is_epoch_millis_near(reference, Duration::from_secs(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "Epoch timestamp at $.secs is 90s from the reference time, exceeding 60s")]
+    fn test_epoch_near_reference_too_far() {
+        let reference = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let json = json!({"secs": 1_699_999_910});
+        JsonPathAssertion::new_for_test(&json, "$.secs")
+            .is_epoch_seconds_near(reference, Duration::from_secs(60));
+    }
+
+    #[test]
+    #[should_panic(expected = "from now, exceeding 60s")]
+    fn test_is_recent_epoch_millis_mistaken_unit() {
+        // Seconds read as milliseconds land in January 1970.
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+        let json = json!({"updated_at": now});
+        JsonPathAssertion::new_for_test(&json, "$.updated_at")
+            .is_recent_epoch_millis(Duration::from_secs(60));
+    }
+
+    #[test]
+    fn test_parses_as_number_between() {
+        let json = json!({"quantity": "12", "price": "19.99"});
+        JsonPathAssertion::new_for_test(&json, "$.quantity").parses_as_i64_between(1, 100);
+        JsonPathAssertion::new_for_test(&json, "$.price").parses_as_f64_between(0.0, 100.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "String at $.quantity ('12.5') is not a valid integer")]
+    fn test_parses_as_i64_invalid() {
+        let json = json!({"quantity": "12.5"});
+        JsonPathAssertion::new_for_test(&json, "$.quantity").parses_as_i64_between(1, 100);
+    }
+
+    #[test]
+    #[should_panic(expected = "Integer parsed from string at $.quantity is not between 1 and 100\nActual: 500")]
+    fn test_parses_as_i64_out_of_range() {
+        let json = json!({"quantity": "500"});
+        JsonPathAssertion::new_for_test(&json, "$.quantity").parses_as_i64_between(1, 100);
+    }
+
+    #[test]
+    fn test_parses_as_number() {
+        let json = json!({"count": "42", "ratio": "2.5", "big": "1e3", "neg": "-0.5"});
+        for path in ["$.count", "$.ratio", "$.big", "$.neg"] {
+            JsonPathAssertion::new_for_test(&json, path).parses_as_number();
+        }
+        JsonPathAssertion::new_for_test(&json, "$.count").parses_as_number_equals(42.0);
+        JsonPathAssertion::new_for_test(&json, "$.ratio").parses_as_number_equals(2.5);
+        JsonPathAssertion::new_for_test(&json, "$.big").parses_as_number_equals(1000.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "String at $.count ('abc') is not a valid number")]
+    fn test_parses_as_number_invalid() {
+        let json = json!({"count": "abc"});
+        JsonPathAssertion::new_for_test(&json, "$.count").parses_as_number();
+    }
+
+    #[test]
+    #[should_panic(expected = "String at $.count ('NaN') is not a valid number")]
+    fn test_parses_as_number_nan() {
+        let json = json!({"count": "NaN"});
+        JsonPathAssertion::new_for_test(&json, "$.count").parses_as_number();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected string at $.count, got Number(42)")]
+    fn test_parses_as_number_not_string() {
+        let json = json!({"count": 42});
+        JsonPathAssertion::new_for_test(&json, "$.count").parses_as_number();
+    }
+
+    #[test]
+    #[should_panic(expected = "Number parsed from string at $.price does not equal 3.15\nActual: 3.14 (from '3.140')")]
+    fn test_parses_as_number_equals_mismatch() {
+        let json = json!({"price": "3.140"});
+        JsonPathAssertion::new_for_test(&json, "$.price").parses_as_number_equals(3.15);
+    }
+
+    #[test]
+    fn test_is_hex_color() {
+        let json = json!({"colors": ["#fff", "#1E90FF", "#00000080", "#aBc"]});
+        for i in 0..4 {
+            JsonPathAssertion::new_for_test(&json, &format!("$.colors[{}]", i)).is_hex_color();
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "String at $.color is not a valid hex color\nActual: #12345")]
+    fn test_is_hex_color_wrong_length() {
+        let json = json!({"color": "#12345"});
+        JsonPathAssertion::new_for_test(&json, "$.color").is_hex_color();
+    }
+
+    #[test]
+    #[should_panic(expected = "String at $.color is not a valid hex color\nActual: 1E90FF")]
+    fn test_is_hex_color_missing_hash() {
+        let json = json!({"color": "1E90FF"});
+        JsonPathAssertion::new_for_test(&json, "$.color").is_hex_color();
+    }
+
+    #[test]
+    fn test_boolean_strings() {
+        let json = json!({"yes": "true", "no": "false", "mixed": "FALSE"});
+        JsonPathAssertion::new_for_test(&json, "$.yes")
+            .is_boolean_string()
+            .boolean_string_equals(true);
+        JsonPathAssertion::new_for_test(&json, "$.no").boolean_string_equals(false);
+        JsonPathAssertion::new_for_test(&json, "$.mixed").is_boolean_string_ignore_case();
+    }
+
+    #[test]
+    #[should_panic(expected = "String at $.mixed is not a boolean string\nActual: FALSE")]
+    fn test_is_boolean_string_is_case_sensitive() {
+        let json = json!({"mixed": "FALSE"});
+        JsonPathAssertion::new_for_test(&json, "$.mixed").is_boolean_string();
+    }
+
+    #[test]
+    #[should_panic(expected = "Boolean string at $.flag does not match\nExpected: true\nActual: false")]
+    fn test_boolean_string_equals_mismatch() {
+        let json = json!({"flag": "false"});
+        JsonPathAssertion::new_for_test(&json, "$.flag").boolean_string_equals(true);
+    }
+
+    #[test]
+    fn test_equals_with_key_order() {
+        let json = json!({"point": {"x": 1, "y": 2}});
+        JsonPathAssertion::new_for_test(&json, "$.point")
+            .equals_with_key_order(json!({"y": 2, "x": 1}), &["x", "y"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Keys at $.point are not in the expected order\nExpected: [\"y\", \"x\"]\nActual: [\"x\", \"y\"]")]
+    fn test_equals_with_key_order_wrong_order() {
+        let json = json!({"point": {"x": 1, "y": 2}});
+        JsonPathAssertion::new_for_test(&json, "$.point")
+            .equals_with_key_order(json!({"x": 1, "y": 2}), &["y", "x"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value mismatch at $.point")]
+    fn test_equals_with_key_order_value_mismatch() {
+        let json = json!({"point": {"x": 1, "y": 2}});
+        JsonPathAssertion::new_for_test(&json, "$.point")
+            .equals_with_key_order(json!({"x": 1, "y": 3}), &["x", "y"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value at $.limits.max_items does not match path-aware predicate\nActual: 100")]
+    fn test_matches_with_path() {
+        let json = json!({"limits": {"max_items": 100, "max_kb": 100}});
+        let small_unless_kb = |path: &str, value: &Value| path.ends_with("_kb") || value.as_u64() < Some(50);
+        JsonPathAssertion::new_for_test(&json, "$.limits.max_kb").matches_with_path(small_unless_kb);
+        JsonPathAssertion::new_for_test(&json, "$.limits.max_items").matches_with_path(small_unless_kb);
+    }
+
+    /// Matcher for even integers
+    #[derive(Debug)]
+    struct EvenMatcher;
+
+    impl JsonMatcher for EvenMatcher {
+        fn matches(&self, value: &Value) -> bool {
+            value.as_i64().is_some_and(|n| n % 2 == 0)
+        }
+
+        fn description(&self) -> String {
+            "is an even integer".to_string()
+        }
+    }
+
+    #[test]
+    fn test_satisfies() {
+        let json = json!({"count": 4, "name": "John"});
+        JsonPathAssertion::new_for_test(&json, "$.count")
+            .satisfies(&crate::TypeMatcher::number())
+            .satisfies(&EvenMatcher);
+        JsonPathAssertion::new_for_test(&json, "$.name")
+            .satisfies(&crate::NotMatcher::new(EvenMatcher));
+    }
+
+    #[test]
+    #[should_panic(expected = "Value at $.count does not satisfy: is an even integer\nActual: 3")]
+    fn test_satisfies_custom_matcher_fails() {
+        let json = json!({"count": 3});
+        JsonPathAssertion::new_for_test(&json, "$.count").satisfies(&EvenMatcher);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value at $.count does not satisfy: is of type string\nActual: 3")]
+    fn test_satisfies_type_matcher_fails() {
+        let json = json!({"count": 3});
+        JsonPathAssertion::new_for_test(&json, "$.count").satisfies(&crate::TypeMatcher::string());
+    }
+
+    #[test]
+    fn test_does_not_satisfy() {
+        let json = json!({"count": 3});
+        JsonPathAssertion::new_for_test(&json, "$.count")
+            .does_not_satisfy(&crate::TypeMatcher::string());
+    }
+
+    #[test]
+    #[should_panic(expected = "Value at $.count unexpectedly satisfies matcher: is of type number\nActual: 3")]
+    fn test_does_not_satisfy_fails() {
+        let json = json!({"count": 3});
+        JsonPathAssertion::new_for_test(&json, "$.count")
+            .does_not_satisfy(&crate::TypeMatcher::number());
+    }
+
+    #[test]
+    fn test_and_also() {
+        let json = json!({"order": {"status": "shipped"}, "shipment": {"carrier": "UPS"}});
+        let test = JsonTest::new(&json);
+        test.assert_path("$.order.status")
+            .is_string()
+            .and_also("$.shipment.carrier", |carrier| {
+                carrier.equals(json!("UPS"));
+            })
+            .equals(json!("shipped"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Value mismatch at $.shipment.carrier")]
+    fn test_and_also_failure_inside_closure() {
+        let json = json!({"order": {"status": "shipped"}, "shipment": {"carrier": "UPS"}});
+        let test = JsonTest::new(&json);
+        test.assert_path("$.order.status")
+            .and_also("$.shipment.carrier", |carrier| {
+                carrier.equals(json!("DHL"));
+            });
+    }
+
+    #[test]
+    fn test_integer_valued_numbers() {
+        let json = json!({"a": 2, "b": 2.0, "c": -3});
+        JsonPathAssertion::new_for_test(&json, "$.a").is_integer_valued().equals_number(2.0);
+        JsonPathAssertion::new_for_test(&json, "$.b").is_integer_valued().equals_number(2.0);
+        JsonPathAssertion::new_for_test(&json, "$.c").is_integer_valued().equals_number(-3.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Number at $.a is not integer-valued\nActual: 2.5")]
+    fn test_is_integer_valued_fraction() {
+        let json = json!({"a": 2.5});
+        JsonPathAssertion::new_for_test(&json, "$.a").is_integer_valued();
+    }
+
+    #[test]
+    #[should_panic(expected = "Value mismatch at $.a\nExpected: 3\nActual: 2.0")]
+    fn test_equals_number_mismatch() {
+        let json = json!({"a": 2.0});
+        JsonPathAssertion::new_for_test(&json, "$.a").equals_number(3.0);
+    }
+
+    #[test]
+    fn test_parse_date() {
+        assert_eq!(parse_date("2024-02-29"), Some((2024, 2, 29)));
+        assert_eq!(parse_date("2000-02-29"), Some((2000, 2, 29)));
+        assert_eq!(parse_date("1900-02-29"), None);
+        assert_eq!(parse_date("2023-02-29"), None);
+        assert_eq!(parse_date("2024-13-40"), None);
+        assert_eq!(parse_date("2024-04-31"), None);
+        assert_eq!(parse_date("2024-4-01"), None);
+        assert_eq!(parse_date("2024-04-01T00:00:00Z"), None);
+        assert_eq!(parse_date("+024-04-01"), None);
+    }
+
+    #[test]
+    fn test_date_ordering() {
+        let json = json!({"ends_on": "2024-03-01"});
+        JsonPathAssertion::new_for_test(&json, "$.ends_on")
+            .is_date()
+            .date_is_after("2024-02-29")
+            .date_is_before("2024-03-02");
+    }
+
+    #[test]
+    #[should_panic(expected = "String at $.day is not a valid date (YYYY-MM-DD)\nActual: 2024-13-40")]
+    fn test_is_date_invalid() {
+        let json = json!({"day": "2024-13-40"});
+        JsonPathAssertion::new_for_test(&json, "$.day").is_date();
+    }
+
+    #[test]
+    #[should_panic(expected = "Date at $.day is not before 2024-03-01\nActual: 2024-03-01")]
+    fn test_date_is_before_same_day() {
+        let json = json!({"day": "2024-03-01"});
+        JsonPathAssertion::new_for_test(&json, "$.day").date_is_before("2024-03-01");
+    }
+
+    #[test]
+    fn test_parse_rfc3339() {
+        assert_eq!(parse_rfc3339("1970-01-01T00:00:00Z"), Some((0, 0)));
+        assert_eq!(parse_rfc3339("2024-03-15T10:30:00Z"), Some((1_710_498_600, 0)));
+        assert_eq!(parse_rfc3339("2024-03-15T12:30:00+02:00"), Some((1_710_498_600, 0)));
+        assert_eq!(parse_rfc3339("2024-03-15t10:30:00.25z"), Some((1_710_498_600, 250_000_000)));
+        assert_eq!(parse_rfc3339("1969-12-31T23:59:59.1234567891-00:00"), Some((-1, 123_456_789)));
+        assert_eq!(parse_rfc3339("2016-12-31T23:59:60Z"), Some((1_483_228_800, 0)));
+        assert_eq!(parse_rfc3339("2024-03-15T10:30:00"), None);
+        assert_eq!(parse_rfc3339("2024-03-15 10:30:00Z"), None);
+        assert_eq!(parse_rfc3339("2024-03-15T24:00:00Z"), None);
+        assert_eq!(parse_rfc3339("2024-02-30T10:30:00Z"), None);
+        assert_eq!(parse_rfc3339("2024-03-15T10:30:00.Z"), None);
+        assert_eq!(parse_rfc3339("2024-03-15T10:30:00+0200"), None);
+        assert_eq!(parse_rfc3339("2024-03-15T10:30Z"), None);
+    }
+
+    #[test]
+    fn test_iso8601_and_rfc3339() {
+        let json = json!({"day": "2024-02-29", "at": "1985-04-12T23:20:50.52-04:00"});
+        JsonPathAssertion::new_for_test(&json, "$.day").is_iso8601_date();
+        JsonPathAssertion::new_for_test(&json, "$.at")
+            .is_rfc3339_datetime()
+            .is_rfc3339_datetime_between("1985-04-13T03:20:50Z", "1985-04-13T03:20:51Z");
+    }
+
+    #[test]
+    #[should_panic(expected = "String at $.day is not an ISO 8601 date (YYYY-MM-DD)\nActual: 20240229")]
+    fn test_is_iso8601_date_invalid() {
+        let json = json!({"day": "20240229"});
+        JsonPathAssertion::new_for_test(&json, "$.day").is_iso8601_date();
+    }
+
+    #[test]
+    #[should_panic(expected = "String at $.at is not an RFC 3339 date-time (YYYY-MM-DDTHH:MM:SS[.fff](Z|±HH:MM))\nActual: 2024-03-15T10:30:00")]
+    fn test_is_rfc3339_datetime_missing_offset() {
+        let json = json!({"at": "2024-03-15T10:30:00"});
+        JsonPathAssertion::new_for_test(&json, "$.at").is_rfc3339_datetime();
+    }
+
+    #[test]
+    #[should_panic(expected = "Timestamp at $.at is not between 2024-03-15T00:00:00Z and 2024-03-15T10:00:00Z\nActual: 2024-03-15T12:00:01+02:00")]
+    fn test_is_rfc3339_datetime_between_out_of_range() {
+        let json = json!({"at": "2024-03-15T12:00:01+02:00"});
+        JsonPathAssertion::new_for_test(&json, "$.at")
+            .is_rfc3339_datetime_between("2024-03-15T00:00:00Z", "2024-03-15T10:00:00Z");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid RFC 3339 bound '2024-03-15', expected YYYY-MM-DDTHH:MM:SSZ")]
+    fn test_is_rfc3339_datetime_between_invalid_bound() {
+        let json = json!({"at": "2024-03-15T10:00:00Z"});
+        JsonPathAssertion::new_for_test(&json, "$.at")
+            .is_rfc3339_datetime_between("2024-03-15", "2024-03-16T00:00:00Z");
+    }
+
+    #[test]
+    fn test_is_base64() {
+        assert!(is_base64("eyJpZCI6NDJ9"));
+        assert!(is_base64("eyJpZCI6NH0="));
+        assert!(is_base64("eyJpZCI6NH0"));
+        assert!(is_base64("a-_b"));
+        assert!(!is_base64(""));
+        assert!(!is_base64("=="));
+        assert!(!is_base64("abcde"));
+        assert!(!is_base64("abc==="));
+        assert!(!is_base64("not base64!"));
+    }
+
+    #[test]
+    fn test_is_cursor() {
+        let json = json!({"next": "eyJpZCI6NDJ9", "prev": null});
+        JsonPathAssertion::new_for_test(&json, "$.next").is_cursor();
+        JsonPathAssertion::new_for_test(&json, "$.prev").is_cursor();
+        JsonPathAssertion::new_for_test(&json, "$.missing").is_cursor();
+    }
+
+    #[test]
+    #[should_panic(expected = "Cursor at $.next is invalid (empty or not base64)\nActual: \"\"")]
+    fn test_is_cursor_empty_string() {
+        let json = json!({"next": ""});
+        JsonPathAssertion::new_for_test(&json, "$.next").is_cursor();
+    }
+
+    #[test]
+    fn test_deep_keys() {
+        let json = json!({"user": {"name": "John", "sessions": [{"id": 1, "meta": {"ip": "127.0.0.1"}}]}});
+        JsonPathAssertion::new_for_test(&json, "$.user")
+            .deep_has_key("ip")
+            .deep_has_key("name")
+            .deep_has_no_key("password");
+    }
+
+    #[test]
+    #[should_panic(expected = "Forbidden key 'password' found at $.user.sessions[1].auth.password")]
+    fn test_deep_has_no_key_found() {
+        let json = json!({"user": {"sessions": [{"id": 1}, {"auth": {"password": "hunter2"}}]}});
+        JsonPathAssertion::new_for_test(&json, "$.user").deep_has_no_key("password");
+    }
+
+    #[test]
+    #[should_panic(expected = "Key 'etag' not found anywhere under $.user")]
+    fn test_deep_has_key_missing() {
+        let json = json!({"user": {"name": "John", "tags": ["etag"]}});
+        JsonPathAssertion::new_for_test(&json, "$.user").deep_has_key("etag");
+    }
+
+    #[test]
+    fn test_all_elements_between() {
+        let json = json!({"p": [0, 0.5, 1]});
+        JsonPathAssertion::new_for_test(&json, "$.p").all_elements_between(0.0, 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Element 1 of $.p (1.5) is outside [0, 1]")]
+    fn test_all_elements_between_out_of_range() {
+        let json = json!({"p": [0.2, 1.5]});
+        JsonPathAssertion::new_for_test(&json, "$.p").all_elements_between(0.0, 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Path $.users[*].name matched 2 values, expected 1\nMatches: [String(\"John\"), String(\"Jane\")]")]
+    fn test_having_match_count() {
+        let json = json!({"users": [{"name": "John"}, {"name": "Jane"}]});
+        JsonPathAssertion::new_for_test(&json, "$.users[0].name").having_match_count(1);
+        JsonPathAssertion::new_for_test(&json, "$.users[5].name").having_match_count(0);
+        JsonPathAssertion::new_for_test(&json, "$.users[*].name").having_match_count(1);
+    }
+
+    #[test]
+    fn test_is_valid_regex() {
+        let json = json!({"pattern": "^order-[0-9]+$"});
+        JsonPathAssertion::new_for_test(&json, "$.pattern").is_valid_regex();
+    }
+
+    #[test]
+    #[should_panic(expected = "String at $.pattern is not a valid regex")]
+    fn test_is_valid_regex_fails() {
+        let json = json!({"pattern": "order-[0-9"});
+        JsonPathAssertion::new_for_test(&json, "$.pattern").is_valid_regex();
+    }
+
+    #[test]
+    fn test_array_length_equals_path() {
+        let json = json!({"labels": ["a", "b"], "values": [1, 2], "extra": [1]});
+        let test = JsonTest::new(&json);
+        test.assert_path("$.labels").array_length_equals_path("$.values");
+    }
+
+    #[test]
+    #[should_panic(expected = "Array at $.labels has length 2, but array at $.extra has length 1")]
+    fn test_array_length_equals_path_mismatch() {
+        let json = json!({"labels": ["a", "b"], "extra": [1]});
+        let test = JsonTest::new(&json);
+        test.assert_path("$.labels").array_length_equals_path("$.extra");
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected array at $.name, got String(\"x\")")]
+    fn test_array_length_equals_path_not_array() {
+        let json = json!({"labels": ["a"], "name": "x"});
+        let test = JsonTest::new(&json);
+        test.assert_path("$.labels").array_length_equals_path("$.name");
+    }
+
+    #[test]
+    fn test_filter_type_hint() {
+        let json = json!({"orders": [{"total": "150"}, {"total": 90}, {"total": "200"}]});
+        assert_eq!(
+            filter_type_hint(&json, "$.orders[?(@.total > 100)]").as_deref(),
+            Some("Hint: field 'total' is a string in 2 of 3 elements; filter comparisons may not apply")
+        );
+        assert_eq!(filter_type_hint(&json, "$.orders[?(@.total == '150')]").as_deref(),
+            Some("Hint: field 'total' is a number in 1 of 3 elements; filter comparisons may not apply"));
+        assert_eq!(filter_type_hint(&json, "$.orders[0].total"), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "No value found at $.orders[?(@.total > 100)]\nHint: field 'total' is a string in 2 of 2 elements")]
+    fn test_strict_filters_hint() {
+        let json = json!({"orders": [{"total": "150"}, {"total": "200"}]});
+        let test = JsonTest::new(&json).with_strict_filters(true);
+        test.assert_path("$.orders[?(@.total > 100)]").equals(json!({"total": 150}));
+    }
+
+    #[test]
+    fn test_strict_filters_off_by_default() {
+        let json = json!({"orders": [{"total": "150"}]});
+        let result = std::panic::catch_unwind(|| {
+            let test = JsonTest::new(&json);
+            test.assert_path("$.orders[?(@.total > 100)]").exists();
+        });
+        let message = result.unwrap_err().downcast::<String>().unwrap();
+        assert_eq!(*message, "Path $.orders[?(@.total > 100)] does not exist");
+    }
+
+    #[test]
+    fn test_string_arrays() {
+        let json = json!({"tags": ["rust", "testing"], "empty": [], "blank": ["a", ""]});
+        let test = JsonTest::new(&json);
+        test.assert_path("$.tags").is_string_array().is_non_empty_string_array();
+        test.assert_path("$.empty").is_string_array();
+        test.assert_path("$.blank").is_string_array();
+    }
+
+    #[test]
+    #[should_panic(expected = "Element at index 1 of $.tags is not a string: Number(2)")]
+    fn test_is_string_array_wrong_element() {
+        let json = json!({"tags": ["a", 2]});
+        JsonPathAssertion::new_for_test(&json, "$.tags").is_string_array();
+    }
+
+    #[test]
+    #[should_panic(expected = "Element at index 1 of $.tags is an empty string")]
+    fn test_is_non_empty_string_array_blank_element() {
+        let json = json!({"tags": ["a", ""]});
+        JsonPathAssertion::new_for_test(&json, "$.tags").is_non_empty_string_array();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected non-empty array at $.tags, got []")]
+    fn test_is_non_empty_string_array_empty() {
+        let json = json!({"tags": []});
+        JsonPathAssertion::new_for_test(&json, "$.tags").is_non_empty_string_array();
+    }
+
+    #[test]
+    fn test_as_typed_vec() {
+        let json = json!({"points": [[1, 2], [3, 4]]});
+        let test = JsonTest::new(&json);
+        let points: Vec<(i32, i32)> = test.assert_path("$.points").as_typed_vec();
+        assert_eq!(points, vec![(1, 2), (3, 4)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed to deserialize element 1 of $.ids into u32: invalid type: string \"2\", expected u32")]
+    fn test_as_typed_vec_bad_element() {
+        let json = json!({"ids": [1, "2"]});
+        let test = JsonTest::new(&json);
+        let _: Vec<u32> = test.assert_path("$.ids").as_typed_vec();
+    }
+
+    #[test]
+    fn test_is_object() {
+        let json = json!({"user": {"name": "John"}, "empty": {}});
+        let test = JsonTest::new(&json);
+        test.assert_path("$.user").is_object();
+        test.assert_path("$.empty").is_object();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected object at $.tags, got Array [String(\"a\")]")]
+    fn test_is_object_not_object() {
+        let json = json!({"tags": ["a"]});
+        JsonPathAssertion::new_for_test(&json, "$.tags").is_object();
+    }
+
+    #[test]
+    #[should_panic(expected = "No value found at $.user")]
+    fn test_is_object_missing() {
+        let json = json!({});
+        JsonPathAssertion::new_for_test(&json, "$.user").is_object();
+    }
+
+    #[test]
+    fn test_is_integer_and_is_float() {
+        let json = json!({"id": 42, "big": u64::MAX, "neg": -3, "price": 42.5});
+        let test = JsonTest::new(&json);
+        test.assert_path("$.id").is_integer();
+        test.assert_path("$.big").is_integer();
+        test.assert_path("$.neg").is_integer();
+        test.assert_path("$.price").is_float();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected integer at $.price, got floating-point value 42.5")]
+    fn test_is_integer_float() {
+        let json = json!({"price": 42.5});
+        JsonPathAssertion::new_for_test(&json, "$.price").is_integer();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected integer at $.id, got floating-point value 42.0")]
+    fn test_is_integer_integer_valued_float() {
+        let json: Value = serde_json::from_str(r#"{"id": 42.0}"#).unwrap();
+        JsonPathAssertion::new_for_test(&json, "$.id").is_integer_valued().is_integer();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected floating-point value with a fractional part at $.price, got 42.0")]
+    fn test_is_float_integer_valued() {
+        let json: Value = serde_json::from_str(r#"{"price": 42.0}"#).unwrap();
+        JsonPathAssertion::new_for_test(&json, "$.price").is_float();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected floating-point value at $.id, got integer 42")]
+    fn test_is_float_integer() {
+        let json = json!({"id": 42});
+        JsonPathAssertion::new_for_test(&json, "$.id").is_float();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected number at $.id, got String(\"42\")")]
+    fn test_is_integer_not_number() {
+        let json = json!({"id": "42"});
+        JsonPathAssertion::new_for_test(&json, "$.id").is_integer();
+    }
+
+    #[test]
+    fn test_inclusive_comparisons() {
+        let json = json!({"n": 10, "f": 2.5});
+        let test = JsonTest::new(&json);
+        test.assert_path("$.n").is_greater_than_or_equal(10).is_greater_than_or_equal(9);
+        test.assert_path("$.n").is_less_than_or_equal(10).is_less_than_or_equal(11);
+        test.assert_path("$.f").is_greater_than_or_equal_f64(2.5).is_less_than_or_equal_f64(2.5);
+        test.assert_path("$.n").is_greater_than_or_equal_f64(9.5).is_less_than_or_equal_f64(10.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Number at $.n is not >= 11\nActual: 10")]
+    fn test_is_greater_than_or_equal_below() {
+        let json = json!({"n": 10});
+        JsonPathAssertion::new_for_test(&json, "$.n").is_greater_than_or_equal(11);
+    }
+
+    #[test]
+    #[should_panic(expected = "Number at $.n is not <= 9\nActual: 10")]
+    fn test_is_less_than_or_equal_above() {
+        let json = json!({"n": 10});
+        JsonPathAssertion::new_for_test(&json, "$.n").is_less_than_or_equal(9);
+    }
+
+    #[test]
+    #[should_panic(expected = "Number at $.f is not <= 2.4\nActual: 2.5")]
+    fn test_is_less_than_or_equal_f64_above() {
+        let json = json!({"f": 2.5});
+        JsonPathAssertion::new_for_test(&json, "$.f").is_less_than_or_equal_f64(2.4);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected number at $.n, got String(\"10\")")]
+    fn test_is_greater_than_or_equal_f64_not_number() {
+        let json = json!({"n": "10"});
+        JsonPathAssertion::new_for_test(&json, "$.n").is_greater_than_or_equal_f64(1.0);
+    }
+
+    #[test]
+    fn test_is_close_to() {
+        let json = json!({"total": 0.30000000000000004, "count": 3});
+        let test = JsonTest::new(&json);
+        test.assert_path("$.total").is_close_to(0.3, 1e-9);
+        test.assert_path("$.count").is_close_to(3.0, 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Number at $.total is not close to 0.3\nExpected: 0.3 (epsilon 0.01)\nActual: 0.35\nDifference: 0.04999999999999999")]
+    fn test_is_close_to_too_far() {
+        let json = json!({"total": 0.35});
+        JsonPathAssertion::new_for_test(&json, "$.total").is_close_to(0.3, 0.01);
+    }
+
+    #[test]
+    #[should_panic(expected = "Number at $.total is not close to NaN")]
+    fn test_is_close_to_nan_expected() {
+        let json = json!({"total": 1.0});
+        JsonPathAssertion::new_for_test(&json, "$.total").is_close_to(f64::NAN, 0.01);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid epsilon NaN: must be a non-negative number")]
+    fn test_is_close_to_nan_epsilon() {
+        let json = json!({"total": 1.0});
+        JsonPathAssertion::new_for_test(&json, "$.total").is_close_to(100.0, f64::NAN);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid epsilon -0.1: must be a non-negative number")]
+    fn test_is_close_to_negative_epsilon() {
+        let json = json!({"total": 1.0});
+        JsonPathAssertion::new_for_test(&json, "$.total").is_close_to(1.0, -0.1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected number at $.total, got String(\"0.3\")")]
+    fn test_is_close_to_not_number() {
+        let json = json!({"total": "0.3"});
+        JsonPathAssertion::new_for_test(&json, "$.total").is_close_to(0.3, 0.01);
+    }
+
+    #[test]
+    #[should_panic(expected = "No value found at $.total")]
+    fn test_is_close_to_missing() {
+        let json = json!({});
+        JsonPathAssertion::new_for_test(&json, "$.total").is_close_to(0.3, 0.01);
+    }
+
+    #[test]
+    fn test_sign_assertions() {
+        let json = json!({"small": 1e-300, "one": 1, "neg": -0.5, "zero": 0, "neg_zero": -0.0, "float_zero": 0.0});
+        let test = JsonTest::new(&json);
+        test.assert_path("$.small").is_positive();
+        test.assert_path("$.one").is_positive();
+        test.assert_path("$.neg").is_negative();
+        test.assert_path("$.zero").is_zero();
+        test.assert_path("$.neg_zero").is_zero();
+        test.assert_path("$.float_zero").is_zero();
+    }
+
+    #[test]
+    #[should_panic(expected = "Number at $.zero is not positive\nActual: 0")]
+    fn test_is_positive_zero() {
+        let json = json!({"zero": 0});
+        JsonPathAssertion::new_for_test(&json, "$.zero").is_positive();
+    }
+
+    #[test]
+    #[should_panic(expected = "Number at $.neg_zero is not negative\nActual: -0.0")]
+    fn test_is_negative_negative_zero() {
+        let json = json!({"neg_zero": -0.0});
+        JsonPathAssertion::new_for_test(&json, "$.neg_zero").is_negative();
+    }
+
+    #[test]
+    #[should_panic(expected = "Number at $.n is not zero\nActual: 0.001")]
+    fn test_is_zero_small_value() {
+        let json = json!({"n": 0.001});
+        JsonPathAssertion::new_for_test(&json, "$.n").is_zero();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected number at $.n, got Bool(true)")]
+    fn test_is_positive_not_number() {
+        let json = json!({"n": true});
+        JsonPathAssertion::new_for_test(&json, "$.n").is_positive();
+    }
+
+    #[test]
+    fn test_nth_first_last() {
+        let json = json!({"users": [{"name": "John"}, {"name": "Jane"}, {"name": "Bob"}]});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.users");
+        let mut second = assertion.nth(1);
+        assert_eq!(second.path_str, "$.users[1]");
+        second.equals(json!({"name": "Jane"}));
+
+        JsonPathAssertion::new_for_test(&json, "$.users").first().equals(json!({"name": "John"}));
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.users");
+        let mut last = assertion.last();
+        assert_eq!(last.path_str, "$.users[2]");
+        last.equals(json!({"name": "Bob"}));
+    }
+
+    #[test]
+    #[should_panic(expected = "Index 3 out of bounds for array at $.roles with length 2")]
+    fn test_nth_out_of_bounds() {
+        let json = json!({"roles": ["admin", "editor"]});
+        JsonPathAssertion::new_for_test(&json, "$.roles").nth(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index 0 out of bounds for array at $.roles with length 0")]
+    fn test_last_of_empty_array() {
+        let json = json!({"roles": []});
+        JsonPathAssertion::new_for_test(&json, "$.roles").last();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected array at $.roles")]
+    fn test_first_of_non_array() {
+        let json = json!({"roles": "admin"});
+        JsonPathAssertion::new_for_test(&json, "$.roles").first();
+    }
+
+    #[test]
+    fn test_contains_all() {
+        let json = json!({"roles": ["user", "editor", "admin"]});
+        JsonPathAssertion::new_for_test(&json, "$.roles")
+            .contains_all(&[json!("admin"), json!("user"), json!("editor")])
+            .contains_all(&[json!("editor")])
+            .contains_all(&[])
+            .does_not_contain(&json!("owner"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Array at $.roles is missing values: [\"owner\",\"guest\"]")]
+    fn test_contains_all_partial_overlap() {
+        let json = json!({"roles": ["user", "admin"]});
+        JsonPathAssertion::new_for_test(&json, "$.roles")
+            .contains_all(&[json!("owner"), json!("admin"), json!("guest")]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected array at $.roles")]
+    fn test_contains_all_non_array() {
+        let json = json!({"roles": "admin"});
+        JsonPathAssertion::new_for_test(&json, "$.roles").contains_all(&[json!("admin")]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Array at $.roles unexpectedly contains \"admin\"")]
+    fn test_does_not_contain_fails() {
+        let json = json!({"roles": ["user", "admin"]});
+        JsonPathAssertion::new_for_test(&json, "$.roles").does_not_contain(&json!("admin"));
+    }
+
+    #[test]
+    fn test_all_match_and_any_match() {
+        let json = json!({"items": [{"price": 10}, {"price": 25}], "empty": []});
+        JsonPathAssertion::new_for_test(&json, "$.items")
+            .all_match(|item| item["price"].as_i64().is_some_and(|price| price > 0))
+            .any_match(|item| item["price"] == 25);
+        JsonPathAssertion::new_for_test(&json, "$.empty").all_match(|_| false);
+    }
+
+    #[test]
+    #[should_panic(expected = "Element at index 1 of $.items does not match predicate\nActual: {\"price\":-5}")]
+    fn test_all_match_fails_with_index() {
+        let json = json!({"items": [{"price": 10}, {"price": -5}, {"price": -1}]});
+        JsonPathAssertion::new_for_test(&json, "$.items")
+            .all_match(|item| item["price"].as_i64().is_some_and(|price| price > 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "No element of $.empty matches predicate")]
+    fn test_any_match_empty_array() {
+        let json = json!({"empty": []});
+        JsonPathAssertion::new_for_test(&json, "$.empty").any_match(|_| true);
+    }
+
+    #[test]
+    fn test_has_unique_elements() {
+        let json = json!({"ids": [1, 2, 3], "mixed": [1, 1.0, "1"], "empty": []});
+        JsonPathAssertion::new_for_test(&json, "$.ids").has_unique_elements();
+        JsonPathAssertion::new_for_test(&json, "$.mixed").has_unique_elements();
+        JsonPathAssertion::new_for_test(&json, "$.empty").has_unique_elements();
+    }
+
+    #[test]
+    #[should_panic(expected = "Array at $.ids has duplicate elements:\n{\"id\":2} at indices [1, 3]")]
+    fn test_has_unique_elements_duplicate_pair() {
+        let json = json!({"ids": [{"id": 1}, {"id": 2}, {"id": 3}, {"id": 2}]});
+        JsonPathAssertion::new_for_test(&json, "$.ids").has_unique_elements();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected array at $.ids")]
+    fn test_has_unique_elements_non_array() {
+        let json = json!({"ids": {"a": 1}});
+        JsonPathAssertion::new_for_test(&json, "$.ids").has_unique_elements();
+    }
+
+    #[test]
+    fn test_is_sorted() {
+        let json = json!({
+            "numbers": [-1, 0, 2.5, 2.5, 10],
+            "names": ["Alice", "Bob", "bob"],
+            "scores": [9, 7.5, 7.5, 1],
+            "single": [true],
+            "empty": []
+        });
+        JsonPathAssertion::new_for_test(&json, "$.numbers").is_sorted();
+        JsonPathAssertion::new_for_test(&json, "$.names").is_sorted();
+        JsonPathAssertion::new_for_test(&json, "$.scores").is_sorted_descending();
+        JsonPathAssertion::new_for_test(&json, "$.single").is_sorted().is_sorted_descending();
+        JsonPathAssertion::new_for_test(&json, "$.empty").is_sorted();
+    }
+
+    #[test]
+    #[should_panic(expected = "Array at $.scores is not sorted in ascending order: 9 at index 0 is followed by 7 at index 1")]
+    fn test_is_sorted_fails_on_descending() {
+        let json = json!({"scores": [9, 7, 1]});
+        JsonPathAssertion::new_for_test(&json, "$.scores").is_sorted();
+    }
+
+    #[test]
+    #[should_panic(expected = "Array at $.names is not sorted in descending order: \"Alice\" at index 1 is followed by \"Bob\" at index 2")]
+    fn test_is_sorted_descending_fails() {
+        let json = json!({"names": ["Carol", "Alice", "Bob"]});
+        JsonPathAssertion::new_for_test(&json, "$.names").is_sorted_descending();
+    }
+
+    #[test]
+    #[should_panic(expected = "Array at $.mixed has incomparable elements: number 1 at index 0 and string \"2\" at index 1")]
+    fn test_is_sorted_mixed_types() {
+        let json = json!({"mixed": [1, "2", 3]});
+        JsonPathAssertion::new_for_test(&json, "$.mixed").is_sorted();
+    }
+
+    #[test]
+    fn test_has_length_bounds() {
+        let json = json!({"results": [1, 2, 3], "empty": []});
+        JsonPathAssertion::new_for_test(&json, "$.results")
+            .has_length_between(3, 3)
+            .has_length_between(1, 5)
+            .has_length_at_least(3)
+            .has_length_at_most(3);
+        JsonPathAssertion::new_for_test(&json, "$.empty")
+            .has_length_at_least(0)
+            .has_length_at_most(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Array at $.results has wrong length\nExpected: between 4 and 10\nActual: 3")]
+    fn test_has_length_between_below_min() {
+        let json = json!({"results": [1, 2, 3]});
+        JsonPathAssertion::new_for_test(&json, "$.results").has_length_between(4, 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "Array at $.results has wrong length\nExpected: at most 2\nActual: 3")]
+    fn test_has_length_at_most_fails() {
+        let json = json!({"results": [1, 2, 3]});
+        JsonPathAssertion::new_for_test(&json, "$.results").has_length_at_most(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Array at $.results has wrong length\nExpected: at least 4\nActual: 3")]
+    fn test_has_length_at_least_fails() {
+        let json = json!({"results": [1, 2, 3]});
+        JsonPathAssertion::new_for_test(&json, "$.results").has_length_at_least(4);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected array at $.name")]
+    fn test_has_length_between_non_array() {
+        let json = json!({"name": "John"});
+        JsonPathAssertion::new_for_test(&json, "$.name").has_length_between(1, 10);
+    }
+
+    #[test]
+    fn test_has_string_length_counts_characters() {
+        let json = json!({"name": "José", "greeting": "hi 👋", "empty": ""});
+        JsonPathAssertion::new_for_test(&json, "$.name")
+            .has_string_length(4)
+            .has_string_length_between(3, 20);
+        JsonPathAssertion::new_for_test(&json, "$.greeting")
+            .has_string_length(4)
+            .has_string_length_between(4, 4);
+        JsonPathAssertion::new_for_test(&json, "$.empty").has_string_length(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "String at $.greeting has wrong length\nExpected: 7 characters\nActual: 4 characters in \"hi 👋\"")]
+    fn test_has_string_length_ignores_bytes() {
+        let json = json!({"greeting": "hi 👋"});
+        JsonPathAssertion::new_for_test(&json, "$.greeting").has_string_length(7);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected: between 3 and 20 characters\nActual: 2 characters in \"jo\"")]
+    fn test_has_string_length_between_fails() {
+        let json = json!({"username": "jo"});
+        JsonPathAssertion::new_for_test(&json, "$.username").has_string_length_between(3, 20);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected string at $.tags")]
+    fn test_has_string_length_non_string() {
+        let json = json!({"tags": ["a", "b"]});
+        JsonPathAssertion::new_for_test(&json, "$.tags").has_string_length(2);
+    }
+
+    #[test]
+    fn test_ignore_case_assertions() {
+        let json = json!({"role": "Admin", "message": "Access DENIED for user"});
+        JsonPathAssertion::new_for_test(&json, "$.role")
+            .equals_ignore_case("admin")
+            .equals_ignore_case("ADMIN")
+            .contains_string_ignore_case("dMi");
+        JsonPathAssertion::new_for_test(&json, "$.message")
+            .contains_string_ignore_case("denied FOR");
+    }
+
+    #[test]
+    #[should_panic(expected = "String at $.role does not equal 'Administrator' ignoring case\nActual: Admin")]
+    fn test_equals_ignore_case_fails() {
+        let json = json!({"role": "Admin"});
+        JsonPathAssertion::new_for_test(&json, "$.role").equals_ignore_case("Administrator");
+    }
+
+    #[test]
+    #[should_panic(expected = "String at $.message does not contain 'Granted' ignoring case\nActual: Access DENIED")]
+    fn test_contains_string_ignore_case_fails() {
+        let json = json!({"message": "Access DENIED"});
+        JsonPathAssertion::new_for_test(&json, "$.message").contains_string_ignore_case("Granted");
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected string at $.role")]
+    fn test_equals_ignore_case_non_string() {
+        let json = json!({"role": 1});
+        JsonPathAssertion::new_for_test(&json, "$.role").equals_ignore_case("1");
+    }
+
+    #[test]
+    fn test_matches_pattern_with_flags() {
+        let json = json!({"role": "ADMIN", "log": "Starting\nERROR: disk full"});
+        JsonPathAssertion::new_for_test(&json, "$.role")
+            .matches_pattern_with_flags("^admin$", RegexFlags::new().case_insensitive(true));
+        JsonPathAssertion::new_for_test(&json, "$.log")
+            .matches_pattern_with_flags("^ERROR", RegexFlags::new().multiline(true))
+            .matches_pattern_with_flags("Starting.ERROR", RegexFlags::new().dot_matches_new_line(true));
+    }
+
+    #[test]
+    #[should_panic(expected = "String at $.role does not match pattern '^admin$' with flags: none\nActual: ADMIN")]
+    fn test_matches_pattern_with_flags_default_is_case_sensitive() {
+        let json = json!({"role": "ADMIN"});
+        JsonPathAssertion::new_for_test(&json, "$.role")
+            .matches_pattern_with_flags("^admin$", RegexFlags::default());
+    }
+
+    #[test]
+    #[should_panic(expected = "with flags: case_insensitive")]
+    fn test_matches_pattern_with_flags_default_is_single_line() {
+        let json = json!({"log": "Starting\nERROR: disk full"});
+        JsonPathAssertion::new_for_test(&json, "$.log")
+            .matches_pattern_with_flags("^error", RegexFlags::new().case_insensitive(true));
+    }
+
+    #[test]
+    fn test_match_count() {
+        let json = json!({"users": [{"role": "admin"}, {"role": "user"}, {"role": "user"}]});
+        JsonPathAssertion::new_for_test(&json, "$.users").match_count(1);
+        JsonPathAssertion::new_for_test(&json, "$.users[*]").match_count(3);
+        JsonPathAssertion::new_for_test(&json, "$.users[?(@.role == 'owner')]").match_count(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Path $.users[*].role matched 3 values, expected 2")]
+    fn test_match_count_fails() {
+        let json = json!({"users": [{"role": "admin"}, {"role": "user"}, {"role": "user"}]});
+        JsonPathAssertion::new_for_test(&json, "$.users[*].role").match_count(2);
+    }
+
+    #[test]
+    fn test_extract() {
+        let json = json!({"user": {"name": "John", "tags": ["a", "b"]}, "users": [{"id": 1}, {"id": 2}]});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.user");
+        assert_eq!(assertion.extract(), json["user"]);
+        assertion.is_object();
+
+        let all = JsonPathAssertion::new_for_test(&json, "$.users[*].id").extract_all();
+        assert_eq!(all, vec![json!(1), json!(2)]);
+        assert!(JsonPathAssertion::new_for_test(&json, "$.missing").extract_all().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "No value found at $.user.email")]
+    fn test_extract_missing() {
+        let json = json!({"user": {"name": "John"}});
+        JsonPathAssertion::new_for_test(&json, "$.user.email").extract();
+    }
+
+    #[test]
+    fn test_equals_unordered() {
+        let json = json!({"ids": [1, 2, 3], "dupes": [2, 1, 2], "name": "John"});
+        JsonPathAssertion::new_for_test(&json, "$.ids")
+            .equals_unordered(json!([3, 1, 2]))
+            .equals_unordered(json!([1, 2, 3]));
+        JsonPathAssertion::new_for_test(&json, "$.dupes").equals_unordered(json!([2, 2, 1]));
+        JsonPathAssertion::new_for_test(&json, "$.name").equals_unordered(json!("John"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Array at $.ids does not match ignoring order\nMissing: []\nUnexpected: [2]")]
+    fn test_equals_unordered_extra_duplicate() {
+        let json = json!({"ids": [1, 2, 2]});
+        JsonPathAssertion::new_for_test(&json, "$.ids").equals_unordered(json!([1, 2]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Missing: [4]\nUnexpected: [3]")]
+    fn test_equals_unordered_different_element() {
+        let json = json!({"ids": [1, 2, 3]});
+        JsonPathAssertion::new_for_test(&json, "$.ids").equals_unordered(json!([4, 2, 1]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Value mismatch at $.ids")]
+    fn test_equals_unordered_non_array() {
+        let json = json!({"ids": {"a": 1}});
+        JsonPathAssertion::new_for_test(&json, "$.ids").equals_unordered(json!([1]));
+    }
+
+    #[test]
+    fn test_equals_with_diff_passes() {
+        let json = json!({"user": {"name": "John", "tags": [1, {"a": null}]}});
+        JsonPathAssertion::new_for_test(&json, "$.user")
+            .equals_with_diff(json!({"tags": [1, {"a": null}], "name": "John"}));
+    }
+
+    #[test]
+    fn test_json_diff() {
+        let actual = json!({
+            "user": {"age": 30, "id": "7", "extra": true},
+            "items": [1, 2, 3],
+            "tags": ["a"]
+        });
+        let expected = json!({
+            "user": {"age": 25, "id": 7, "email": "john@example.com"},
+            "items": [1, 2],
+            "tags": ["a", "b"]
+        });
+
+        assert_eq!(
+            json_diff(&actual, &expected, "$"),
+            vec![
+                "$.items[2]: unexpected in actual, found 3",
+                "$.tags[1]: missing in actual, expected \"b\"",
+                "$.user.age: expected 25, actual 30",
+                "$.user.email: missing in actual, expected \"john@example.com\"",
+                "$.user.id: expected number 7, actual string \"7\"",
+                "$.user.extra: unexpected in actual, found true",
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Value mismatch at $.user (2 differences)\n$.user.address.city: expected \"Paris\", actual \"Lyon\"\n$.user.roles[1]: missing in actual, expected \"editor\"")]
+    fn test_equals_with_diff_fails() {
+        let json = json!({"user": {"address": {"city": "Lyon"}, "roles": ["admin"]}});
+        JsonPathAssertion::new_for_test(&json, "$.user")
+            .equals_with_diff(json!({"address": {"city": "Paris"}, "roles": ["admin", "editor"]}));
+    }
+
+    fn snapshot_dir(test: &str) -> PathBuf {
+        env::temp_dir().join(format!("json-test-snapshots-{}-{}", test, std::process::id()))
+    }
+
+    #[test]
+    fn test_snapshot_update_then_match() {
+        let dir = snapshot_dir("update");
+        let json = json!({"user": {"name": "John", "roles": ["admin"]}});
+        JsonPathAssertion::new_for_test(&json, "$.user").matches_snapshot_in(&dir, "user", true);
+        let written = fs::read_to_string(dir.join("user.json")).unwrap();
+        JsonPathAssertion::new_for_test(&json, "$.user").matches_snapshot_in(&dir, "user", false);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(serde_json::from_str::<Value>(&written).unwrap(), json["user"]);
+        assert!(written.ends_with("}\n"));
+    }
+
+    #[test]
+    fn test_snapshot_mismatch() {
+        let dir = snapshot_dir("mismatch");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("user.json"), r#"{"name": "Jane", "roles": ["admin"]}"#).unwrap();
+        let json = json!({"user": {"name": "John", "roles": ["admin"]}});
+        let result = std::panic::catch_unwind(|| {
+            JsonPathAssertion::new_for_test(&json, "$.user").matches_snapshot_in(&dir, "user", false);
+        });
+        fs::remove_dir_all(&dir).unwrap();
+
+        let message = *result.unwrap_err().downcast::<String>().unwrap();
+        assert!(message.starts_with("Value at $.user does not match snapshot "), "{}", message);
+        assert!(message.contains("(1 difference)\n$.user.name: expected \"Jane\", actual \"John\"\n"), "{}", message);
+    }
+
+    #[test]
+    #[should_panic(expected = "Run with UPDATE_SNAPSHOTS=1 to create it")]
+    fn test_snapshot_missing_file() {
+        let json = json!({"user": {"name": "John"}});
+        JsonPathAssertion::new_for_test(&json, "$.user")
+            .matches_snapshot_in(&snapshot_dir("missing"), "user", false);
+    }
+
+    #[test]
+    fn test_is_one_of() {
+        let json = json!({"status": "shipped", "priority": 2});
+        JsonPathAssertion::new_for_test(&json, "$.status")
+            .is_one_of(&[json!("pending"), json!("shipped"), json!("delivered")])
+            .is_not_one_of(&[json!("cancelled"), json!("Shipped")]);
+        JsonPathAssertion::new_for_test(&json, "$.priority")
+            .is_one_of(&[json!(1), json!(2), json!(3)])
+            .is_not_one_of(&[json!("2")]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value at $.status is not one of [\"pending\",\"shipped\",\"delivered\"]\nActual: \"lost\"")]
+    fn test_is_one_of_fails() {
+        let json = json!({"status": "lost"});
+        JsonPathAssertion::new_for_test(&json, "$.status")
+            .is_one_of(&[json!("pending"), json!("shipped"), json!("delivered")]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value at $.status is unexpectedly one of [\"cancelled\",\"refunded\"]\nActual: \"refunded\"")]
+    fn test_is_not_one_of_fails() {
+        let json = json!({"status": "refunded"});
+        JsonPathAssertion::new_for_test(&json, "$.status")
+            .is_not_one_of(&[json!("cancelled"), json!("refunded")]);
+    }
+
+    #[test]
+    fn test_equals_path() {
+        let json = json!({"order": {"total": 42.5, "items": [1, 2]}, "summary": {"grand_total": 42.5, "items": [1, 2]}});
+        let test = JsonTest::new(&json);
+        test.assert_path("$.order.total").equals_path("$.summary.grand_total");
+        test.assert_path("$.order.items").equals_path("$.summary.items");
+    }
+
+    #[test]
+    #[should_panic(expected = "Value at $.order.total does not equal value at $.summary.grand_total\n$.order.total: 42.5\n$.summary.grand_total: 40.0")]
+    fn test_equals_path_mismatch() {
+        let json = json!({"order": {"total": 42.5}, "summary": {"grand_total": 40.0}});
+        let test = JsonTest::new(&json);
+        test.assert_path("$.order.total").equals_path("$.summary.grand_total");
+    }
+
+    #[test]
+    #[should_panic(expected = "No value found at $.summary.total")]
+    fn test_equals_path_missing_other() {
+        let json = json!({"order": {"total": 42.5}, "summary": {}});
+        let test = JsonTest::new(&json);
+        test.assert_path("$.order.total").equals_path("$.summary.total");
+    }
+
+    #[test]
+    fn test_embedded_json() {
+        let json = json!({"metadata": "{\"source\": \"import\", \"rows\": [1, 2]}"});
+        JsonPathAssertion::new_for_test(&json, "$.metadata").is_valid_json();
+        assert_eq!(
+            JsonPathAssertion::new_for_test(&json, "$.metadata").parse_json(),
+            json!({"source": "import", "rows": [1, 2]})
+        );
+
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.metadata");
+        let mut parsed = assertion.embedded_json();
+        assert_eq!(parsed.path_str, "$.metadata (parsed JSON)");
+        parsed.is_object().equals(json!({"source": "import", "rows": [1, 2]}));
+
+        let json = json!({"count": "42"});
+        JsonPathAssertion::new_for_test(&json, "$.count").embedded_json().equals(json!(42));
+    }
+
+    #[test]
+    #[should_panic(expected = "String at $.metadata is not valid JSON: EOF while parsing a value at line 1 column 5\nActual: {\"a\":")]
+    fn test_is_valid_json_invalid() {
+        let json = json!({"metadata": "{\"a\":"});
+        JsonPathAssertion::new_for_test(&json, "$.metadata").is_valid_json();
+    }
+
+    #[test]
+    #[should_panic(expected = "String at $.metadata is not valid JSON: expected ident at line 1 column 2")]
+    fn test_parse_json_invalid() {
+        let json = json!({"metadata": "not json"});
+        JsonPathAssertion::new_for_test(&json, "$.metadata").parse_json();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected string at $.metadata, got Object {\"a\": Number(1)}")]
+    fn test_embedded_json_not_string() {
+        let json = json!({"metadata": {"a": 1}});
+        JsonPathAssertion::new_for_test(&json, "$.metadata").embedded_json();
+    }
+
+    #[test]
+    fn test_is_subset_of_path() {
+        let json = json!({
+            "defaults": {"retries": 3, "http": {"timeout": 30}},
+            "effective": {"retries": 3, "debug": true, "http": {"timeout": 30, "proxy": null}}
+        });
+        let test = JsonTest::new(&json);
+        test.assert_path("$.defaults").is_subset_of_path("$.effective");
+    }
+
+    #[test]
+    #[should_panic(expected = "$.effective.http.timeout is missing")]
+    fn test_is_subset_of_path_missing_key() {
+        let json = json!({
+            "defaults": {"http": {"timeout": 30}},
+            "effective": {"http": {"proxy": null}}
+        });
+        let test = JsonTest::new(&json);
+        test.assert_path("$.defaults").is_subset_of_path("$.effective");
+    }
+
+    #[test]
+    #[should_panic(expected = "value mismatch at $.effective.retries\nExpected: 3\nActual: 5")]
+    fn test_is_subset_of_path_mismatch() {
+        let json = json!({"defaults": {"retries": 3}, "effective": {"retries": 5}});
+        let test = JsonTest::new(&json);
+        test.assert_path("$.defaults").is_subset_of_path("$.effective");
+    }
+
+    #[test]
+    fn test_keys_match_path() {
+        let json = json!({
+            "labels": {"cpu": "CPU usage", "mem": "Memory"},
+            "values": {"mem": 0.42, "cpu": 0.9}
+        });
+        let test = JsonTest::new(&json);
+        test.assert_path("$.labels").keys_match_path("$.values");
+    }
+
+    #[test]
+    #[should_panic(expected = "Keys at $.labels do not match keys at $.values\nOnly in $.labels: [\"cpu\"]\nOnly in $.values: [\"disk\"]")]
+    fn test_keys_match_path_mismatch() {
+        let json = json!({
+            "labels": {"cpu": "CPU usage", "mem": "Memory"},
+            "values": {"mem": 0.42, "disk": 0.1}
+        });
+        let test = JsonTest::new(&json);
+        test.assert_path("$.labels").keys_match_path("$.values");
+    }
+
+    #[test]
+    fn test_numeric_stats() {
+        let json = json!({"values": [2, 4.5, 1.5]});
+        let assertion = JsonPathAssertion::new_for_test(&json, "$.values");
+        let stats = assertion.numeric_stats();
+        assert_eq!(stats.min, 1.5);
+        assert_eq!(stats.max, 4.5);
+        assert_eq!(stats.sum, 8.0);
+        assert_eq!(stats.count, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Element at index 1 of $.values is not a number")]
+    fn test_numeric_stats_non_numeric() {
+        let json = json!({"values": [1, "2", 3]});
+        JsonPathAssertion::new_for_test(&json, "$.values").numeric_stats();
+    }
+
+    #[test]
+    fn test_all_true_and_all_false() {
+        let json = json!({"passed": [true, true], "failed": [false], "none": []});
+        JsonPathAssertion::new_for_test(&json, "$.passed").all_true();
+        JsonPathAssertion::new_for_test(&json, "$.failed").all_false();
+        JsonPathAssertion::new_for_test(&json, "$.none").all_true().all_false();
+    }
+
+    #[test]
+    #[should_panic(expected = "Element at index 2 of $.checks is false, expected all true")]
+    fn test_all_true_fails() {
+        let json = json!({"checks": [true, true, false]});
+        JsonPathAssertion::new_for_test(&json, "$.checks").all_true();
+    }
+
+    #[test]
+    #[should_panic(expected = "Element at index 1 of $.checks is not a boolean: Number(0)")]
+    fn test_all_false_mixed_types() {
+        let json = json!({"checks": [false, 0]});
+        JsonPathAssertion::new_for_test(&json, "$.checks").all_false();
+    }
+}
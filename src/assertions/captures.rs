@@ -0,0 +1,219 @@
+use super::base::JsonPathAssertion;
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// The capture groups of a regular expression matched against a string.
+///
+/// Created by `JsonPathAssertion::captures()`. `group` and `named_group` return
+/// a new assertion over the captured substring as a JSON string, so captured
+/// parts can be checked with the usual assertions.
+///
+/// # Examples
+///
+/// ```rust
+/// # use json_test::JsonTest;
+/// # use serde_json::json;
+/// # let data = json!({"created_at": "2024-03-15T10:30:00Z"});
+/// # let test = JsonTest::new(&data);
+/// test.assert_path("$.created_at")
+///     .captures(r"^(?<year>\d{4})-(\d{2})")
+///     .named_group("year")
+///     .equals(json!("2024"));
+/// ```
+pub struct CaptureAssertion<'s, 'a> {
+    assertion: &'s mut JsonPathAssertion<'a>,
+    pattern: String,
+    groups: Vec<Option<String>>,
+    names: Vec<(String, usize)>,
+}
+
+impl<'s, 'a> CaptureAssertion<'s, 'a> {
+    pub(crate) fn new(assertion: &'s mut JsonPathAssertion<'a>, pattern: &str) -> Self {
+        let regex = regex::Regex::new(pattern)
+            .unwrap_or_else(|e| panic!("Invalid regex pattern: {}", e));
+
+        let names = regex.capture_names()
+            .enumerate()
+            .filter_map(|(index, name)| Some((name?.to_string(), index)))
+            .collect();
+
+        let groups = match assertion.first_value() {
+            Some(Value::String(s)) => match regex.captures(s) {
+                Some(captures) => captures.iter()
+                    .map(|group| group.map(|m| m.as_str().to_string()))
+                    .collect(),
+                None => {
+                    assertion.report(format!(
+                        "String at {} does not match pattern '{}'\nActual: {}",
+                        assertion.path_str, pattern, s
+                    ));
+                    Vec::new()
+                }
+            },
+            Some(v) => {
+                assertion.report(format!("Expected string at {}, got {:?}", assertion.path_str, v));
+                Vec::new()
+            }
+            None => {
+                assertion.report_error(assertion.missing_error(None));
+                Vec::new()
+            }
+        };
+
+        Self {
+            assertion,
+            pattern: pattern.to_string(),
+            groups,
+            names,
+        }
+    }
+
+    /// Creates an assertion over the capture group at `index`.
+    ///
+    /// Group 0 is the whole match and numbered groups start at 1. The new
+    /// assertion's path is `<path> (group <index>)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"created_at": "2024-03-15"});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.created_at")
+    ///     .captures(r"(\d{4})-(\d{2})")
+    ///     .group(1)
+    ///     .equals(json!("2024"));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the pattern has no group `index`
+    /// - Panics if the group did not take part in the match
+    pub fn group(self, index: usize) -> JsonPathAssertion<'a> {
+        let path_str = format!("{} (group {})", self.assertion.path_str, index);
+        if !self.groups.is_empty() && index >= self.groups.len() {
+            self.assertion.report(format!(
+                "Capture group {} does not exist in pattern '{}' ({} groups)",
+                index, self.pattern, self.groups.len() - 1
+            ));
+        }
+        self.captured(path_str, index)
+    }
+
+    /// Creates an assertion over the capture group named `name`.
+    ///
+    /// The new assertion's path is `<path> (group '<name>')`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"version": "v2.14.1"});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.version")
+    ///     .captures(r"^v(?<major>\d+)\.(?<minor>\d+)")
+    ///     .named_group("minor")
+    ///     .equals(json!("14"));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the pattern has no group called `name`
+    /// - Panics if the group did not take part in the match
+    pub fn named_group(self, name: &str) -> JsonPathAssertion<'a> {
+        let path_str = format!("{} (group '{}')", self.assertion.path_str, name);
+        match self.names.iter().find(|(n, _)| n == name) {
+            Some(&(_, index)) => self.captured(path_str, index),
+            None => {
+                self.assertion.report(format!(
+                    "Capture group '{}' does not exist in pattern '{}'",
+                    name, self.pattern
+                ));
+                self.captured(path_str, usize::MAX)
+            }
+        }
+    }
+
+    fn captured(self, path_str: String, index: usize) -> JsonPathAssertion<'a> {
+        let value = match self.groups.get(index) {
+            Some(Some(captured)) => Some(Value::String(captured.clone())),
+            Some(None) => {
+                self.assertion.report(format!(
+                    "Capture group at {} did not take part in the match",
+                    path_str
+                ));
+                None
+            }
+            None => None,
+        };
+
+        JsonPathAssertion {
+            path_str,
+            current_values: value.map(Cow::Owned).into_iter().collect(),
+            test: self.assertion.test,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertions::base::JsonPathAssertion;
+    use serde_json::json;
+
+    #[test]
+    fn test_numbered_captures() {
+        let json = json!({"created_at": "2024-03-15T10:30:00Z"});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.created_at");
+        let mut year = assertion.captures(r"(\d{4})-(\d{2})").group(1);
+        assert_eq!(year.path_str, "$.created_at (group 1)");
+        year.equals(json!("2024")).has_string_length(4);
+
+        JsonPathAssertion::new_for_test(&json, "$.created_at")
+            .captures(r"(\d{4})-(\d{2})")
+            .group(0)
+            .equals(json!("2024-03"));
+    }
+
+    #[test]
+    fn test_named_captures() {
+        let json = json!({"version": "v2.14.1"});
+        JsonPathAssertion::new_for_test(&json, "$.version")
+            .captures(r"^v(?<major>\d+)\.(?<minor>\d+)")
+            .named_group("minor")
+            .equals(json!("14"));
+    }
+
+    #[test]
+    #[should_panic(expected = "String at $.date does not match pattern '^(\\d{4})'\nActual: March")]
+    fn test_captures_no_match() {
+        let json = json!({"date": "March"});
+        JsonPathAssertion::new_for_test(&json, "$.date").captures(r"^(\d{4})");
+    }
+
+    #[test]
+    #[should_panic(expected = "Capture group 2 does not exist in pattern '(\\d{4})' (1 groups)")]
+    fn test_missing_group_index() {
+        let json = json!({"date": "2024-03-15"});
+        JsonPathAssertion::new_for_test(&json, "$.date").captures(r"(\d{4})").group(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Capture group 'day' does not exist in pattern '(?<year>\\d{4})'")]
+    fn test_missing_group_name() {
+        let json = json!({"date": "2024-03-15"});
+        JsonPathAssertion::new_for_test(&json, "$.date")
+            .captures(r"(?<year>\d{4})")
+            .named_group("day");
+    }
+
+    #[test]
+    #[should_panic(expected = "Capture group at $.id (group 2) did not take part in the match")]
+    fn test_unmatched_optional_group() {
+        let json = json!({"id": "user"});
+        JsonPathAssertion::new_for_test(&json, "$.id")
+            .captures(r"^(user)(_\d+)?$")
+            .group(2);
+    }
+}
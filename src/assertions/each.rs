@@ -0,0 +1,247 @@
+use super::base::JsonPathAssertion;
+use crate::error::JsonPathError;
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// Assertions applied to every value matched by a path.
+///
+/// Created by `JsonPathAssertion::each()`. Most assertions only inspect the
+/// first match, which silently ignores the rest of a wildcard or filter
+/// result. Each method here checks every match instead, and a failure names
+/// the failing match as `<path> (match <index>)` along with its value. A path
+/// that matched nothing fails every assertion. Methods can be chained, and
+/// `and()` returns to the original assertion.
+///
+/// # Examples
+///
+/// ```rust
+/// # use json_test::JsonTest;
+/// # use serde_json::json;
+/// # let data = json!({"users": [{"age": 25}, {"age": 30}, {"age": 42}]});
+/// # let test = JsonTest::new(&data);
+/// test.assert_path("$.users[*].age")
+///     .each()
+///     .is_integer()
+///     .is_greater_than(0);
+/// ```
+pub struct EachAssertion<'s, 'a> {
+    assertion: &'s mut JsonPathAssertion<'a>,
+}
+
+#[allow(clippy::result_large_err)]
+impl<'s, 'a> EachAssertion<'s, 'a> {
+    pub(crate) fn new(assertion: &'s mut JsonPathAssertion<'a>) -> Self {
+        Self { assertion }
+    }
+
+    /// Runs `check` on each match in turn, reporting the first failure.
+    fn check_each<F>(self, check: F) -> Self
+    where
+        F: Fn(&JsonPathAssertion<'_>) -> Result<(), JsonPathError>,
+    {
+        if self.assertion.current_values.is_empty() {
+            self.assertion.report_error(self.assertion.missing_error(None));
+            return self;
+        }
+
+        for (index, value) in self.assertion.current_values.iter().enumerate() {
+            let single = JsonPathAssertion {
+                path_str: format!("{} (match {})", self.assertion.path_str, index),
+                current_values: vec![Cow::Borrowed(value.as_ref())],
+                test: None,
+            };
+            if let Err(error) = check(&single) {
+                self.assertion.report_error(error);
+                break;
+            }
+        }
+        self
+    }
+
+    /// Asserts that every matched value equals `expected`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"items": [{"status": "paid"}, {"status": "paid"}]});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.items[*].status")
+    ///     .each()
+    ///     .equals(json!("paid"));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the path matched nothing
+    /// - Panics on the first value that doesn't equal `expected`
+    pub fn equals(self, expected: Value) -> Self {
+        self.check_each(|single| single.check_equals(&expected))
+    }
+
+    /// Asserts that every matched value is a string.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the path matched nothing
+    /// - Panics on the first value that is not a string
+    pub fn is_string(self) -> Self {
+        self.check_each(|single| single.check_type("string", Value::is_string))
+    }
+
+    /// Asserts that every matched value is a number.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the path matched nothing
+    /// - Panics on the first value that is not a number
+    pub fn is_number(self) -> Self {
+        self.check_each(|single| single.check_type("number", Value::is_number))
+    }
+
+    /// Asserts that every matched value is a number stored as an integer.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the path matched nothing
+    /// - Panics on the first value that is not an integer
+    pub fn is_integer(self) -> Self {
+        self.check_each(|single| single.check_integer())
+    }
+
+    /// Asserts that every matched value is a boolean.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the path matched nothing
+    /// - Panics on the first value that is not a boolean
+    pub fn is_boolean(self) -> Self {
+        self.check_each(|single| single.check_type("boolean", Value::is_boolean))
+    }
+
+    /// Asserts that every matched value is an array.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the path matched nothing
+    /// - Panics on the first value that is not an array
+    pub fn is_array(self) -> Self {
+        self.check_each(|single| single.check_type("array", Value::is_array))
+    }
+
+    /// Asserts that every matched value is an object.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the path matched nothing
+    /// - Panics on the first value that is not an object
+    pub fn is_object(self) -> Self {
+        self.check_each(|single| single.check_type("object", Value::is_object))
+    }
+
+    /// Asserts that every matched value is a number greater than `value`.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the path matched nothing
+    /// - Panics on the first value that is not a number greater than `value`
+    pub fn is_greater_than(self, value: i64) -> Self {
+        self.check_each(|single| single.check_greater_than(value))
+    }
+
+    /// Asserts that every matched value is a number less than `value`.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the path matched nothing
+    /// - Panics on the first value that is not a number less than `value`
+    pub fn is_less_than(self, value: i64) -> Self {
+        self.check_each(|single| single.check_less_than(value))
+    }
+
+    /// Asserts that every matched value is a number between `min` and `max` (inclusive).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"ratings": [{"stars": 4}, {"stars": 5}, {"stars": 1}]});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.ratings[*].stars")
+    ///     .each()
+    ///     .is_between(1, 5);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the path matched nothing
+    /// - Panics on the first value that is not a number in the range
+    pub fn is_between(self, min: i64, max: i64) -> Self {
+        self.check_each(|single| single.check_between(min, max))
+    }
+
+    /// Returns to the original assertion to continue the chain.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"users": [{"name": "John"}, {"name": "Jane"}]});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.users[*].name")
+    ///     .each()
+    ///     .is_string()
+    ///     .and()
+    ///     .having_match_count(2);
+    /// ```
+    pub fn and(self) -> &'s mut JsonPathAssertion<'a> {
+        self.assertion
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertions::base::JsonPathAssertion;
+    use serde_json::json;
+
+    #[test]
+    fn test_each_passes_for_every_match() {
+        let json = json!({"users": [{"age": 25, "active": true}, {"age": 30, "active": true}]});
+        JsonPathAssertion::new_for_test(&json, "$.users[*].age")
+            .each()
+            .is_number()
+            .is_integer()
+            .is_greater_than(18)
+            .is_less_than(100)
+            .is_between(25, 30);
+        JsonPathAssertion::new_for_test(&json, "$.users[*].active")
+            .each()
+            .is_boolean()
+            .equals(json!(true));
+        JsonPathAssertion::new_for_test(&json, "$.users[*]").each().is_object();
+    }
+
+    #[test]
+    #[should_panic(expected = "Number at $.users[*].age (match 2) is not greater than 0\nActual: -1")]
+    fn test_each_reports_failing_match() {
+        let json = json!({"users": [{"age": 25}, {"age": 30}, {"age": -1}, {"age": -2}]});
+        JsonPathAssertion::new_for_test(&json, "$.users[*].age").each().is_greater_than(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected string at $.tags[*] (match 1), got Number(2)")]
+    fn test_each_type_failure() {
+        let json = json!({"tags": ["a", 2, "c"]});
+        JsonPathAssertion::new_for_test(&json, "$.tags[*]").each().is_string();
+    }
+
+    #[test]
+    #[should_panic(expected = "No value found at $.users[*].age")]
+    fn test_each_requires_matches() {
+        let json = json!({"users": []});
+        JsonPathAssertion::new_for_test(&json, "$.users[*].age").each().is_number();
+    }
+}
@@ -0,0 +1,444 @@
+use super::base::JsonPathAssertion;
+use crate::error::{type_name, JsonPathError};
+use serde_json::{json, Number, Value};
+use std::collections::HashMap;
+
+/// Result-returning counterparts of the equality, type and comparison assertions.
+///
+/// Each `try_` method performs the same check as the panicking method of the
+/// same name, and on failure returns `JsonPathError::AssertionFailed` carrying
+/// the message the panicking method would have shown. This lets harnesses
+/// collect failures without `catch_unwind` and compose assertions with `?`.
+#[allow(clippy::result_large_err)]
+impl<'a> JsonPathAssertion<'a> {
+    /// Like `equals`, but returns an error instead of panicking.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::{JsonPathError, JsonTest};
+    /// # use serde_json::json;
+    /// fn check_user(test: &JsonTest) -> Result<(), JsonPathError> {
+    ///     test.assert_path("$.user.name")
+    ///         .try_is_string()?
+    ///         .try_equals(json!("John"))?;
+    ///     test.assert_path("$.user.age")
+    ///         .try_is_between(18, 100)?;
+    ///     Ok(())
+    /// }
+    ///
+    /// let data = json!({"user": {"name": "John", "age": 30}});
+    /// assert!(check_user(&JsonTest::new(&data)).is_ok());
+    ///
+    /// let data = json!({"user": {"name": "Jane", "age": 30}});
+    /// let err = check_user(&JsonTest::new(&data)).unwrap_err();
+    /// assert!(matches!(err, JsonPathError::AssertionFailed { ref path, .. } if path == "$.user.name"));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `JsonPathError::AssertionFailed` with the expected value if no
+    /// value exists at the path or the value doesn't match.
+    pub fn try_equals(&mut self, expected: Value) -> Result<&mut Self, JsonPathError> {
+        self.check_equals(&expected)?;
+        Ok(self)
+    }
+
+    /// Like `is_string`, but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns `JsonPathError::AssertionFailed` if no value exists at the path
+    /// or the value is not a string.
+    pub fn try_is_string(&mut self) -> Result<&mut Self, JsonPathError> {
+        self.check_type("string", Value::is_string)?;
+        Ok(self)
+    }
+
+    /// Like `is_number`, but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns `JsonPathError::AssertionFailed` if no value exists at the path
+    /// or the value is not a number.
+    pub fn try_is_number(&mut self) -> Result<&mut Self, JsonPathError> {
+        self.check_type("number", Value::is_number)?;
+        Ok(self)
+    }
+
+    /// Like `is_integer`, but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns `JsonPathError::AssertionFailed` if no value exists at the path,
+    /// the value is not a number or the number is a floating-point value.
+    pub fn try_is_integer(&mut self) -> Result<&mut Self, JsonPathError> {
+        self.check_integer()?;
+        Ok(self)
+    }
+
+    /// Like `is_float`, but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns `JsonPathError::AssertionFailed` if no value exists at the path,
+    /// the value is not a number or the number is integer-valued.
+    pub fn try_is_float(&mut self) -> Result<&mut Self, JsonPathError> {
+        self.check_float()?;
+        Ok(self)
+    }
+
+    /// Like `is_array`, but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns `JsonPathError::AssertionFailed` if no value exists at the path
+    /// or the value is not an array.
+    pub fn try_is_array(&mut self) -> Result<&mut Self, JsonPathError> {
+        self.check_type("array", Value::is_array)?;
+        Ok(self)
+    }
+
+    /// Like `is_object`, but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns `JsonPathError::AssertionFailed` if no value exists at the path
+    /// or the value is not an object.
+    pub fn try_is_object(&mut self) -> Result<&mut Self, JsonPathError> {
+        self.check_type("object", Value::is_object)?;
+        Ok(self)
+    }
+
+    /// Like `is_greater_than`, but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns `JsonPathError::AssertionFailed` if no value exists at the path,
+    /// the value is not a number or the comparison fails.
+    pub fn try_is_greater_than(&mut self, value: i64) -> Result<&mut Self, JsonPathError> {
+        self.check_greater_than(value)?;
+        Ok(self)
+    }
+
+    /// Like `is_less_than`, but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns `JsonPathError::AssertionFailed` if no value exists at the path,
+    /// the value is not a number or the comparison fails.
+    pub fn try_is_less_than(&mut self, value: i64) -> Result<&mut Self, JsonPathError> {
+        self.check_less_than(value)?;
+        Ok(self)
+    }
+
+    /// Like `is_between`, but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns `JsonPathError::AssertionFailed` if no value exists at the path,
+    /// the value is not a number or the value is outside the range.
+    pub fn try_is_between(&mut self, min: i64, max: i64) -> Result<&mut Self, JsonPathError> {
+        self.check_between(min, max)?;
+        Ok(self)
+    }
+
+    /// Like `is_greater_than_or_equal`, but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns `JsonPathError::AssertionFailed` if no value exists at the path,
+    /// the value is not a number or the comparison fails.
+    pub fn try_is_greater_than_or_equal(&mut self, value: i64) -> Result<&mut Self, JsonPathError> {
+        self.check_at_least(value)?;
+        Ok(self)
+    }
+
+    /// Like `is_less_than_or_equal`, but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns `JsonPathError::AssertionFailed` if no value exists at the path,
+    /// the value is not a number or the comparison fails.
+    pub fn try_is_less_than_or_equal(&mut self, value: i64) -> Result<&mut Self, JsonPathError> {
+        self.check_at_most(value)?;
+        Ok(self)
+    }
+
+    /// Like `is_greater_than_or_equal_f64`, but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns `JsonPathError::AssertionFailed` if no value exists at the path,
+    /// the value is not a number or the comparison fails.
+    pub fn try_is_greater_than_or_equal_f64(&mut self, value: f64) -> Result<&mut Self, JsonPathError> {
+        self.check_at_least_f64(value)?;
+        Ok(self)
+    }
+
+    /// Like `is_less_than_or_equal_f64`, but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns `JsonPathError::AssertionFailed` if no value exists at the path,
+    /// the value is not a number or the comparison fails.
+    pub fn try_is_less_than_or_equal_f64(&mut self, value: f64) -> Result<&mut Self, JsonPathError> {
+        self.check_at_most_f64(value)?;
+        Ok(self)
+    }
+
+    pub(crate) fn check_equals(&self, expected: &Value) -> Result<(), JsonPathError> {
+        match self.first_value() {
+            Some(actual) if actual == expected => Ok(()),
+            Some(actual) => Err(JsonPathError::assertion_failed(
+                format!(
+                    "Value mismatch at {}\nExpected: {}\nActual: {}",
+                    self.path_str, expected, actual
+                ),
+                self.path_str.as_str(),
+                actual.clone(),
+                Some(expected.clone()),
+                HashMap::new(),
+            )),
+            None => Err(self.missing_error(Some(expected.clone()))),
+        }
+    }
+
+    pub(crate) fn check_type(&self, expected_type: &str, matches: fn(&Value) -> bool) -> Result<(), JsonPathError> {
+        match self.first_value() {
+            Some(v) if matches(v) => Ok(()),
+            Some(v) => {
+                let mut context = HashMap::new();
+                context.insert("Expected Type".to_string(), expected_type.to_string());
+                context.insert("Actual Type".to_string(), type_name(v));
+                Err(JsonPathError::assertion_failed(
+                    format!("Expected {} at {}, got {:?}", expected_type, self.path_str, v),
+                    self.path_str.as_str(),
+                    v.clone(),
+                    None,
+                    context,
+                ))
+            }
+            None => Err(self.missing_error(None)),
+        }
+    }
+
+    pub(crate) fn check_integer(&self) -> Result<(), JsonPathError> {
+        self.check_number("integer", Value::Null, |n| n.is_i64() || n.is_u64(), |n| {
+            format!("Expected integer at {}, got floating-point value {}", self.path_str, n)
+        })
+    }
+
+    pub(crate) fn check_float(&self) -> Result<(), JsonPathError> {
+        self.check_number("float", Value::Null, |n| n.is_f64() && n.as_f64().is_some_and(|x| x.fract() != 0.0), |n| {
+            if n.is_f64() {
+                format!("Expected floating-point value with a fractional part at {}, got {}", self.path_str, n)
+            } else {
+                format!("Expected floating-point value at {}, got integer {}", self.path_str, n)
+            }
+        })
+    }
+
+    pub(crate) fn check_greater_than(&self, value: i64) -> Result<(), JsonPathError> {
+        self.check_number(">", json!(value), |n| n.as_i64().is_some_and(|x| x > value), |n| {
+            format!("Number at {} is not greater than {}\nActual: {}", self.path_str, value, n)
+        })
+    }
+
+    pub(crate) fn check_less_than(&self, value: i64) -> Result<(), JsonPathError> {
+        self.check_number("<", json!(value), |n| n.as_i64().is_some_and(|x| x < value), |n| {
+            format!("Number at {} is not less than {}\nActual: {}", self.path_str, value, n)
+        })
+    }
+
+    pub(crate) fn check_between(&self, min: i64, max: i64) -> Result<(), JsonPathError> {
+        self.check_number("between", json!([min, max]), |n| n.as_i64().is_some_and(|x| x >= min && x <= max), |n| {
+            format!("Number at {} is not between {} and {}\nActual: {}", self.path_str, min, max, n)
+        })
+    }
+
+    pub(crate) fn check_at_least(&self, value: i64) -> Result<(), JsonPathError> {
+        self.check_number(">=", json!(value), |n| n.as_i64().is_some_and(|x| x >= value), |n| {
+            format!("Number at {} is not >= {}\nActual: {}", self.path_str, value, n)
+        })
+    }
+
+    pub(crate) fn check_at_most(&self, value: i64) -> Result<(), JsonPathError> {
+        self.check_number("<=", json!(value), |n| n.as_i64().is_some_and(|x| x <= value), |n| {
+            format!("Number at {} is not <= {}\nActual: {}", self.path_str, value, n)
+        })
+    }
+
+    pub(crate) fn check_at_least_f64(&self, value: f64) -> Result<(), JsonPathError> {
+        self.check_number(">=", json!(value), |n| n.as_f64().is_some_and(|x| x >= value), |n| {
+            format!("Number at {} is not >= {}\nActual: {}", self.path_str, value, n)
+        })
+    }
+
+    pub(crate) fn check_at_most_f64(&self, value: f64) -> Result<(), JsonPathError> {
+        self.check_number("<=", json!(value), |n| n.as_f64().is_some_and(|x| x <= value), |n| {
+            format!("Number at {} is not <= {}\nActual: {}", self.path_str, value, n)
+        })
+    }
+
+    /// Checks the current value is a number accepted by `accept`, describing
+    /// rejected numbers with `describe`.
+    fn check_number<A, D>(
+        &self,
+        operation: &str,
+        comparison: Value,
+        accept: A,
+        describe: D,
+    ) -> Result<(), JsonPathError>
+    where
+        A: Fn(&Number) -> bool,
+        D: Fn(&Number) -> String,
+    {
+        self.check_type("number", Value::is_number)?;
+        match self.first_value() {
+            Some(Value::Number(n)) if accept(n) => Ok(()),
+            Some(actual @ Value::Number(n)) => {
+                let mut context = HashMap::new();
+                context.insert("Operation".to_string(), operation.to_string());
+                if !comparison.is_null() {
+                    context.insert("Comparison Value".to_string(), comparison.to_string());
+                }
+                Err(JsonPathError::assertion_failed(
+                    describe(n),
+                    self.path_str.as_str(),
+                    actual.clone(),
+                    None,
+                    context,
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn failure(result: Result<&mut JsonPathAssertion, JsonPathError>) -> (String, String, Value, Option<Value>) {
+        failure_of(result.map(|_| ()))
+    }
+
+    fn failure_of(result: Result<(), JsonPathError>) -> (String, String, Value, Option<Value>) {
+        match result {
+            Err(JsonPathError::AssertionFailed { message, path, actual, expected, .. }) => {
+                (message, path, actual, expected)
+            }
+            Err(other) => panic!("unexpected error: {}", other),
+            Ok(_) => panic!("expected an error"),
+        }
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn check_all(json: &Value) -> Result<(), JsonPathError> {
+        JsonPathAssertion::new_for_test(json, "$.name")
+            .try_is_string()?
+            .try_equals(json!("John"))?;
+        JsonPathAssertion::new_for_test(json, "$.age")
+            .try_is_number()?
+            .try_is_integer()?
+            .try_is_between(18, 100)?
+            .try_is_greater_than(29)?
+            .try_is_less_than(31)?
+            .try_is_greater_than_or_equal(30)?
+            .try_is_less_than_or_equal(30)?
+            .try_is_greater_than_or_equal_f64(29.5)?
+            .try_is_less_than_or_equal_f64(30.0)?;
+        JsonPathAssertion::new_for_test(json, "$.price").try_is_float()?;
+        JsonPathAssertion::new_for_test(json, "$.tags").try_is_array()?;
+        JsonPathAssertion::new_for_test(json, "$.user").try_is_object()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_methods_pass() {
+        let json = json!({"name": "John", "age": 30, "price": 9.5, "tags": [], "user": {}});
+        assert!(check_all(&json).is_ok());
+    }
+
+    #[test]
+    fn test_try_methods_stop_at_first_error() {
+        let json = json!({"name": "John", "age": 17, "price": 9.5, "tags": [], "user": {}});
+        let (message, path, ..) = failure_of(check_all(&json));
+        assert_eq!(path, "$.age");
+        assert_eq!(message, "Number at $.age is not between 18 and 100\nActual: 17");
+    }
+
+    #[test]
+    fn test_try_equals_mismatch() {
+        let json = json!({"name": "Jane"});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.name");
+        let (message, path, actual, expected) = failure(assertion.try_equals(json!("John")));
+
+        assert_eq!(message, "Value mismatch at $.name\nExpected: \"John\"\nActual: \"Jane\"");
+        assert_eq!(path, "$.name");
+        assert_eq!(actual, json!("Jane"));
+        assert_eq!(expected, Some(json!("John")));
+    }
+
+    #[test]
+    fn test_try_equals_missing() {
+        let json = json!({});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.name");
+        let (message, _, actual, expected) = failure(assertion.try_equals(json!("John")));
+
+        assert_eq!(message, "No value found at $.name");
+        assert_eq!(actual, Value::Null);
+        assert_eq!(expected, Some(json!("John")));
+    }
+
+    #[test]
+    fn test_try_type_mismatch() {
+        let json = json!({"age": "30"});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.age");
+        let err = assertion.try_is_number().unwrap_err();
+
+        match err {
+            JsonPathError::AssertionFailed { message, actual, context, .. } => {
+                assert_eq!(message, "Expected number at $.age, got String(\"30\")");
+                assert_eq!(actual, json!("30"));
+                assert_eq!(context["Expected Type"], "number");
+                assert_eq!(context["Actual Type"], "string");
+            }
+            other => panic!("unexpected error: {}", other),
+        }
+    }
+
+    #[test]
+    fn test_try_comparison_failed() {
+        let json = json!({"age": 15});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.age");
+        let err = assertion.try_is_greater_than(18).unwrap_err();
+
+        match err {
+            JsonPathError::AssertionFailed { message, actual, context, .. } => {
+                assert_eq!(message, "Number at $.age is not greater than 18\nActual: 15");
+                assert_eq!(actual, json!(15));
+                assert_eq!(context["Operation"], ">");
+                assert_eq!(context["Comparison Value"], "18");
+            }
+            other => panic!("unexpected error: {}", other),
+        }
+    }
+
+    #[test]
+    fn test_try_comparison_on_non_number() {
+        let json = json!({"age": "15"});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.age");
+        let (message, ..) = failure(assertion.try_is_less_than_or_equal(18));
+        assert_eq!(message, "Expected number at $.age, got String(\"15\")");
+    }
+
+    #[test]
+    fn test_try_is_float_integer() {
+        let json = json!({"id": 42});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.id");
+        let (message, ..) = failure(assertion.try_is_float());
+        assert_eq!(message, "Expected floating-point value at $.id, got integer 42");
+    }
+}
@@ -1,3 +1,41 @@
+/// Fails the enclosing assertion method with a formatted message.
+///
+/// The failure is reported through `JsonPathAssertion::report`, which panics
+/// unless the test is collecting failures, and the method then returns the
+/// assertion so the chain can continue.
+macro_rules! fail {
+    ($assertion:ident, $($arg:tt)+) => {{
+        let message = format!($($arg)+);
+        $assertion.report(message);
+        return $assertion;
+    }};
+}
+
+/// Like `fail!`, but reports an already built `JsonPathError`.
+macro_rules! fail_with {
+    ($assertion:ident, $error:expr) => {{
+        let error = $error;
+        $assertion.report_error(error);
+        return $assertion;
+    }};
+}
+
+/// Unwraps a `Result<T, JsonPathError>`, failing the enclosing assertion
+/// method with the error.
+macro_rules! ok_or_fail {
+    ($assertion:ident, $result:expr) => {
+        match $result {
+            Ok(value) => value,
+            Err(error) => fail_with!($assertion, error),
+        }
+    };
+}
+
 pub mod base;
+pub mod captures;
+pub mod each;
+pub mod fallible;
+pub mod negated;
+pub mod numeric_stats;
 pub mod property_assertions;
-pub mod property_matcher;
\ No newline at end of file
+pub mod property_matcher;
@@ -0,0 +1,280 @@
+use crate::error::type_name;
+use serde_json::Value;
+
+/// Inverted assertions on the current value.
+///
+/// Created by `JsonPathAssertion::not()`. Each method passes when the
+/// corresponding positive assertion would fail, and fails when it would pass.
+/// The value must still exist: a missing path fails every negated assertion.
+/// Methods can be chained, and `and()` returns to the positive assertion.
+///
+/// # Examples
+///
+/// ```rust
+/// # use json_test::JsonTest;
+/// # use serde_json::json;
+/// # let data = json!({"user": {"roles": ["user", "editor"]}});
+/// # let test = JsonTest::new(&data);
+/// test.assert_path("$.user.roles")
+///     .not()
+///     .contains(&json!("admin"))
+///     .is_string()
+///     .and()
+///     .has_length(2);
+/// ```
+pub struct NegatedAssertion<'s, 'a> {
+    assertion: &'s mut super::base::JsonPathAssertion<'a>,
+}
+
+impl<'s, 'a> NegatedAssertion<'s, 'a> {
+    pub(crate) fn new(assertion: &'s mut super::base::JsonPathAssertion<'a>) -> Self {
+        Self { assertion }
+    }
+
+    /// Returns the current value, reporting a failure if the path matched nothing.
+    fn value(&self) -> Option<&Value> {
+        let value = self.assertion.first_value();
+        if value.is_none() {
+            self.assertion.report_error(self.assertion.missing_error(None));
+        }
+        value
+    }
+
+    /// Asserts that the value does not equal `unexpected`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"status": "active"});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.status")
+    ///     .not()
+    ///     .equals(json!("deleted"));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value equals `unexpected`
+    pub fn equals(self, unexpected: Value) -> Self {
+        if self.value() == Some(&unexpected) {
+            self.assertion.report(format!(
+                "Value at {} unexpectedly equals {}",
+                self.assertion.path_str, unexpected
+            ));
+        }
+        self
+    }
+
+    /// Asserts that the array does not contain `unexpected`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"roles": ["user", "editor"]});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.roles")
+    ///     .not()
+    ///     .contains(&json!("admin"));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not an array
+    /// - Panics if the array contains `unexpected`
+    pub fn contains(self, unexpected: &Value) -> Self {
+        match self.value() {
+            Some(Value::Array(arr)) if arr.contains(unexpected) => self.assertion.report(format!(
+                "Array at {} unexpectedly contains {}",
+                self.assertion.path_str, unexpected
+            )),
+            Some(Value::Array(_)) | None => {}
+            Some(v) => self.assertion.report(format!(
+                "Expected array at {}, got {:?}",
+                self.assertion.path_str, v
+            )),
+        }
+        self
+    }
+
+    /// Asserts that the string does not match the regular expression.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"username": "john_doe"});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.username")
+    ///     .not()
+    ///     .matches_pattern(r"\s");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the pattern is invalid
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is not a string
+    /// - Panics if the string matches the pattern
+    pub fn matches_pattern(self, pattern: &str) -> Self {
+        let regex = regex::Regex::new(pattern)
+            .unwrap_or_else(|e| panic!("Invalid regex pattern: {}", e));
+
+        match self.value() {
+            Some(Value::String(s)) if regex.is_match(s) => self.assertion.report(format!(
+                "String at {} unexpectedly matches pattern '{}'\nActual: {}",
+                self.assertion.path_str, pattern, s
+            )),
+            Some(Value::String(_)) | None => {}
+            Some(v) => self.assertion.report(format!(
+                "Expected string at {}, got {:?}",
+                self.assertion.path_str, v
+            )),
+        }
+        self
+    }
+
+    /// Asserts that the value is not a string.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is a string
+    pub fn is_string(self) -> Self {
+        self.not_of_type(Value::is_string)
+    }
+
+    /// Asserts that the value is not a number.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is a number
+    pub fn is_number(self) -> Self {
+        self.not_of_type(Value::is_number)
+    }
+
+    /// Asserts that the value is not an array.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is an array
+    pub fn is_array(self) -> Self {
+        self.not_of_type(Value::is_array)
+    }
+
+    /// Asserts that the value is not an object.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value exists at the path
+    /// - Panics if the value is an object
+    pub fn is_object(self) -> Self {
+        self.not_of_type(Value::is_object)
+    }
+
+    fn not_of_type(self, has_type: fn(&Value) -> bool) -> Self {
+        if let Some(v) = self.value().filter(|v| has_type(v)) {
+            self.assertion.report(format!(
+                "Value at {} is unexpectedly {} {}\nActual: {}",
+                self.assertion.path_str,
+                if v.is_array() || v.is_object() { "an" } else { "a" },
+                type_name(v),
+                v
+            ));
+        }
+        self
+    }
+
+    /// Returns to the positive assertion to continue the chain.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// # let data = json!({"id": "42"});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.id")
+    ///     .not()
+    ///     .is_number()
+    ///     .and()
+    ///     .is_string();
+    /// ```
+    pub fn and(self) -> &'s mut super::base::JsonPathAssertion<'a> {
+        self.assertion
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertions::base::JsonPathAssertion;
+    use serde_json::json;
+
+    #[test]
+    fn test_negated_assertions_pass() {
+        let json = json!({"roles": ["user"], "name": "John", "id": 7, "user": {}});
+        JsonPathAssertion::new_for_test(&json, "$.roles")
+            .not()
+            .contains(&json!("admin"))
+            .equals(json!([]))
+            .is_object()
+            .is_string();
+        JsonPathAssertion::new_for_test(&json, "$.name")
+            .not()
+            .matches_pattern(r"^\d+$")
+            .is_number()
+            .is_array();
+        JsonPathAssertion::new_for_test(&json, "$.user").not().is_array();
+        JsonPathAssertion::new_for_test(&json, "$.id").not().is_string().and().equals(json!(7));
+    }
+
+    #[test]
+    #[should_panic(expected = "Array at $.roles unexpectedly contains \"admin\"")]
+    fn test_not_contains_fails() {
+        let json = json!({"roles": ["user", "admin"]});
+        JsonPathAssertion::new_for_test(&json, "$.roles").not().contains(&json!("admin"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Value at $.status unexpectedly equals \"deleted\"")]
+    fn test_not_equals_fails() {
+        let json = json!({"status": "deleted"});
+        JsonPathAssertion::new_for_test(&json, "$.status").not().equals(json!("deleted"));
+    }
+
+    #[test]
+    #[should_panic(expected = "String at $.name unexpectedly matches pattern '^J'\nActual: John")]
+    fn test_not_matches_pattern_fails() {
+        let json = json!({"name": "John"});
+        JsonPathAssertion::new_for_test(&json, "$.name").not().matches_pattern("^J");
+    }
+
+    #[test]
+    #[should_panic(expected = "Value at $.tags is unexpectedly an array\nActual: [\"a\"]")]
+    fn test_not_is_array_fails() {
+        let json = json!({"tags": ["a"]});
+        JsonPathAssertion::new_for_test(&json, "$.tags").not().is_array();
+    }
+
+    #[test]
+    #[should_panic(expected = "Value at $.id is unexpectedly a number\nActual: 7")]
+    fn test_not_is_number_fails() {
+        let json = json!({"id": 7});
+        JsonPathAssertion::new_for_test(&json, "$.id").not().is_number();
+    }
+
+    #[test]
+    #[should_panic(expected = "No value found at $.missing")]
+    fn test_not_requires_value() {
+        let json = json!({});
+        JsonPathAssertion::new_for_test(&json, "$.missing").not().is_string();
+    }
+}
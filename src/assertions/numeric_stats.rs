@@ -0,0 +1,161 @@
+/// Summary statistics computed from a numeric array.
+///
+/// Created by `JsonPathAssertion::numeric_stats()`. The computed values are
+/// available as public fields, and the assertion methods consume and return
+/// the stats so several bounds can be checked in one chain.
+///
+/// # Examples
+///
+/// ```rust
+/// # use json_test::JsonTest;
+/// # use serde_json::json;
+/// # let data = json!({"latencies": [1.2, 1.5, 1.8, 2.1]});
+/// # let test = JsonTest::new(&data);
+/// let stats = test.assert_path("$.latencies")
+///     .numeric_stats()
+///     .count_equals(4)
+///     .mean_between(1.0, 2.0)
+///     .max_at_most(10.0);
+///
+/// assert_eq!(stats.min, 1.2);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumericStats {
+    /// Smallest element of the array.
+    pub min: f64,
+    /// Largest element of the array.
+    pub max: f64,
+    /// Sum of all elements.
+    pub sum: f64,
+    /// Arithmetic mean of all elements.
+    pub mean: f64,
+    /// Number of elements.
+    pub count: usize,
+    path_str: String,
+}
+
+impl NumericStats {
+    pub(crate) fn from_values(values: &[f64], path_str: &str) -> Self {
+        let sum: f64 = values.iter().sum();
+        Self {
+            min: values.iter().copied().fold(f64::INFINITY, f64::min),
+            max: values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+            sum,
+            mean: sum / values.len() as f64,
+            count: values.len(),
+            path_str: path_str.to_string(),
+        }
+    }
+
+    /// Asserts that the array has the expected number of elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the element count differs from `expected`.
+    pub fn count_equals(self, expected: usize) -> Self {
+        if self.count != expected {
+            panic!(
+                "Array at {} has {} elements, expected {}",
+                self.path_str, self.count, expected
+            );
+        }
+        self
+    }
+
+    /// Asserts that the sum of the elements is between `min` and `max` (inclusive).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sum falls outside the range.
+    pub fn sum_between(self, min: f64, max: f64) -> Self {
+        if self.sum < min || self.sum > max {
+            panic!(
+                "Sum of array at {} is {}, expected between {} and {}",
+                self.path_str, self.sum, min, max
+            );
+        }
+        self
+    }
+
+    /// Asserts that the mean of the elements is between `min` and `max` (inclusive).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mean falls outside the range.
+    pub fn mean_between(self, min: f64, max: f64) -> Self {
+        if self.mean < min || self.mean > max {
+            panic!(
+                "Mean of array at {} is {}, expected between {} and {}",
+                self.path_str, self.mean, min, max
+            );
+        }
+        self
+    }
+
+    /// Asserts that the smallest element is at least `min`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the smallest element is below `min`.
+    pub fn min_at_least(self, min: f64) -> Self {
+        if self.min < min {
+            panic!(
+                "Minimum of array at {} is {}, expected at least {}",
+                self.path_str, self.min, min
+            );
+        }
+        self
+    }
+
+    /// Asserts that the largest element is at most `max`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the largest element is above `max`.
+    pub fn max_at_most(self, max: f64) -> Self {
+        if self.max > max {
+            panic!(
+                "Maximum of array at {} is {}, expected at most {}",
+                self.path_str, self.max, max
+            );
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_values() {
+        let stats = NumericStats::from_values(&[3.0, 1.0, 2.0], "$.values");
+        assert_eq!(stats.min, 1.0);
+        assert_eq!(stats.max, 3.0);
+        assert_eq!(stats.sum, 6.0);
+        assert_eq!(stats.mean, 2.0);
+        assert_eq!(stats.count, 3);
+    }
+
+    #[test]
+    fn test_stats_assertions() {
+        NumericStats::from_values(&[1.0, 2.0, 3.0], "$.values")
+            .count_equals(3)
+            .sum_between(6.0, 6.0)
+            .mean_between(1.5, 2.5)
+            .min_at_least(1.0)
+            .max_at_most(3.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Mean of array at $.values is 2, expected between 3 and 4")]
+    fn test_mean_out_of_range() {
+        NumericStats::from_values(&[1.0, 2.0, 3.0], "$.values").mean_between(3.0, 4.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Maximum of array at $.values is 3, expected at most 2.5")]
+    fn test_max_too_large() {
+        NumericStats::from_values(&[1.0, 2.0, 3.0], "$.values").max_at_most(2.5);
+    }
+}
@@ -1,5 +1,9 @@
 use serde_json::Value;
+use std::collections::HashMap;
+use crate::assertions::base::JsonPathAssertion;
 use crate::assertions::property_matcher::PropertyMatcher;
+use crate::error::type_name;
+use crate::matchers::{JsonMatcher, TypeMatcher};
 
 /// Trait providing property testing capabilities for JSON objects.
 pub trait PropertyAssertions<'a> {
@@ -11,7 +15,7 @@ pub trait PropertyAssertions<'a> {
     /// # use json_test::{JsonTest, PropertyAssertions};
     /// # use serde_json::json;
     /// # let data = json!({"user": {"name": "John"}});
-    /// # let mut test = JsonTest::new(&data);
+    /// # let test = JsonTest::new(&data);
     /// test.assert_path("$.user")
     ///     .has_property("name");
     /// ```
@@ -20,7 +24,7 @@ pub trait PropertyAssertions<'a> {
     ///
     /// - Panics if the value is not an object
     /// - Panics if the property doesn't exist
-    fn has_property(&'a mut self, name: &str) -> &'a mut Self;
+    fn has_property(&mut self, name: &str) -> &mut Self;
 
     /// Asserts that the object has all the specified properties.
     ///
@@ -30,7 +34,7 @@ pub trait PropertyAssertions<'a> {
     /// # use json_test::{JsonTest, PropertyAssertions};
     /// # use serde_json::json;
     /// # let data = json!({"user": {"name": "John", "age": 30}});
-    /// # let mut test = JsonTest::new(&data);
+    /// # let test = JsonTest::new(&data);
     /// test.assert_path("$.user")
     ///     .has_properties(["name", "age"]);
     /// ```
@@ -39,7 +43,53 @@ pub trait PropertyAssertions<'a> {
     ///
     /// - Panics if the value is not an object
     /// - Panics if any of the properties don't exist
-    fn has_properties<I, S>(&'a mut self, names: I) -> &'a mut Self
+    fn has_properties<I, S>(&mut self, names: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>;
+
+    /// Asserts that the object does not have the specified property.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::{JsonTest, PropertyAssertions};
+    /// # use serde_json::json;
+    /// # let data = json!({"user": {"name": "John", "email": "john@example.com"}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.user")
+    ///     .has_property("email")
+    ///     .does_not_have_property("password");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the value is not an object
+    /// - Panics if the property exists
+    fn does_not_have_property(&mut self, name: &str) -> &mut Self;
+
+    /// Asserts that the object has exactly the specified properties and no others.
+    ///
+    /// Unlike `has_properties`, extra properties fail the assertion. Missing and
+    /// unexpected properties are reported separately.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::{JsonTest, PropertyAssertions};
+    /// # use serde_json::json;
+    /// # let data = json!({"user": {"id": 1, "name": "John"}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.user")
+    ///     .has_exactly_properties(["name", "id"]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the value is not an object
+    /// - Panics if any of the properties don't exist
+    /// - Panics if the object has any other properties
+    fn has_exactly_properties<I, S>(&mut self, names: I) -> &mut Self
     where
         I: IntoIterator<Item = S>,
         S: AsRef<str>;
@@ -52,7 +102,7 @@ pub trait PropertyAssertions<'a> {
     /// # use json_test::{JsonTest, PropertyAssertions};
     /// # use serde_json::json;
     /// # let data = json!({"user": {"name": "John", "age": 30}});
-    /// # let mut test = JsonTest::new(&data);
+    /// # let test = JsonTest::new(&data);
     /// test.assert_path("$.user")
     ///     .has_property_count(2);
     /// ```
@@ -60,8 +110,34 @@ pub trait PropertyAssertions<'a> {
     /// # Panics
     ///
     /// - Panics if the value is not an object
-    /// - Panics if the number of properties doesn't match the expected countfn has_property_count(&'a mut self, expected: usize) -> &'a mut Self;
-    fn has_property_count(&'a mut self, expected: usize) -> &'a mut Self;
+    /// - Panics if the number of properties doesn't match the expected countfn has_property_count(&mut self, expected: usize) -> &mut Self;
+    fn has_property_count(&mut self, expected: usize) -> &mut Self;
+
+    /// Asserts that a property exists and its value has the given JSON type.
+    ///
+    /// `ty` is one of `"string"`, `"number"`, `"boolean"`, `"null"`, `"array"`
+    /// or `"object"`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::{JsonTest, PropertyAssertions};
+    /// # use serde_json::json;
+    /// # let data = json!({"user": {"name": "John", "age": 30, "tags": []}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.user")
+    ///     .has_property_of_type("name", "string")
+    ///     .has_property_of_type("age", "number")
+    ///     .has_property_of_type("tags", "array");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the value is not an object
+    /// - Panics if the property doesn't exist
+    /// - Panics if the property's value is not of type `ty`
+    /// - Panics if `ty` is not a JSON type name
+    fn has_property_of_type(&mut self, name: &str, ty: &str) -> &mut Self;
 
     /// Asserts that the object has the expected number of properties matching a predicate.
     ///
@@ -71,7 +147,7 @@ pub trait PropertyAssertions<'a> {
     /// # use json_test::{JsonTest, PropertyAssertions};
     /// # use serde_json::json;
     /// # let data = json!({"user": {"meta_created": "2024-01-01", "meta_updated": "2024-01-02", "name": "John"}});
-    /// # let mut test = JsonTest::new(&data);
+    /// # let test = JsonTest::new(&data);
     /// test.assert_path("$.user")
     ///     .has_property_count_matching(|key| key.starts_with("meta_"), 2);
     /// ```
@@ -80,8 +156,28 @@ pub trait PropertyAssertions<'a> {
     ///
     /// - Panics if the value is not an object
     /// - Panics if the number of matching properties doesn't equal the expected count
+    fn has_property_count_matching<F>(&mut self, predicate: F, expected: usize) -> &mut Self
+    where
+        F: Fn(&str) -> bool;
 
-    fn has_property_count_matching<F>(&'a mut self, predicate: F, expected: usize) -> &'a mut Self
+    /// Asserts that no property name matches a predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::{JsonTest, PropertyAssertions};
+    /// # use serde_json::json;
+    /// # let data = json!({"user": {"name": "John", "email": "john@example.com"}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.user")
+    ///     .has_no_properties_matching(|key| key.starts_with("_tmp"));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the value is not an object
+    /// - Panics if any property name matches the predicate
+    fn has_no_properties_matching<F>(&mut self, predicate: F) -> &mut Self
     where
         F: Fn(&str) -> bool;
 
@@ -93,7 +189,7 @@ pub trait PropertyAssertions<'a> {
     /// # use json_test::{JsonTest, PropertyAssertions};
     /// # use serde_json::json;
     /// # let data = json!({"user": {"name": "John", "age": 30}});
-    /// # let mut test = JsonTest::new(&data);
+    /// # let test = JsonTest::new(&data);
     /// test.assert_path("$.user")
     ///     .has_property_value("name", json!("John"));
     /// ```
@@ -103,7 +199,27 @@ pub trait PropertyAssertions<'a> {
     /// - Panics if the value is not an object
     /// - Panics if the property doesn't exist
     /// - Panics if the property value doesn't match the expected value
-    fn has_property_value(&'a mut self, name: &str, expected: Value) -> &'a mut Self;
+    fn has_property_value(&mut self, name: &str, expected: Value) -> &mut Self;
+
+    /// Asserts that a property's value is one of the allowed values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::{JsonTest, PropertyAssertions};
+    /// # use serde_json::json;
+    /// # let data = json!({"order": {"status": "shipped"}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.order")
+    ///     .has_property_value_one_of("status", &[json!("pending"), json!("shipped"), json!("delivered")]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the value is not an object
+    /// - Panics if the property doesn't exist
+    /// - Panics if the property value is not one of `allowed`
+    fn has_property_value_one_of(&mut self, name: &str, allowed: &[Value]) -> &mut Self;
 
     /// Asserts that a property's value satisfies a predicate.
     ///
@@ -113,7 +229,7 @@ pub trait PropertyAssertions<'a> {
     /// # use json_test::{JsonTest, PropertyAssertions};
     /// # use serde_json::json;
     /// # let data = json!({"user": {"age": 30}});
-    /// # let mut test = JsonTest::new(&data);
+    /// # let test = JsonTest::new(&data);
     /// test.assert_path("$.user")
     ///     .has_property_matching("age", |v| v.as_u64().unwrap_or(0) > 18);
     /// ```
@@ -123,11 +239,99 @@ pub trait PropertyAssertions<'a> {
     /// - Panics if the value is not an object
     /// - Panics if the property doesn't exist
     /// - Panics if the property value doesn't satisfy the predicate
-
-    fn has_property_matching<F>(&'a mut self, name: &str, predicate: F) -> &'a mut Self
+    fn has_property_matching<F>(&mut self, name: &str, predicate: F) -> &mut Self
     where
         F: Fn(&Value) -> bool;
 
+    /// Asserts that no two properties of the object have equal values.
+    ///
+    /// Values are compared by their serialized JSON, so this suits objects used
+    /// as bidirectional maps, such as enum-to-code tables.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::{JsonTest, PropertyAssertions};
+    /// # use serde_json::json;
+    /// # let data = json!({"status_codes": {"active": "A", "inactive": "I", "pending": "P"}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.status_codes")
+    ///     .values_are_unique();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the value is not an object
+    /// - Panics if two properties have the same value, naming both keys
+    fn values_are_unique(&mut self) -> &mut Self;
+
+    /// Asserts that the object is a money value with an integer `amount` and a currency code.
+    ///
+    /// The `currency` property must be a three-letter uppercase code such as
+    /// `"USD"`. The amount is expected in minor units (e.g. cents).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::{JsonTest, PropertyAssertions};
+    /// # use serde_json::json;
+    /// # let data = json!({"order": {"total": {"amount": 1299, "currency": "USD"}}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.order.total")
+    ///     .is_money();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the value is not an object
+    /// - Panics if `amount` is missing or not an integer
+    /// - Panics if `currency` is missing or not a three-letter uppercase code
+    fn is_money(&mut self) -> &mut Self;
+
+    /// Asserts that the object is a money value with the given amount and currency.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::{JsonTest, PropertyAssertions};
+    /// # use serde_json::json;
+    /// # let data = json!({"order": {"total": {"amount": 1299, "currency": "EUR"}}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.order.total")
+    ///     .money_equals(1299, "EUR");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the value is not a valid money object (see `is_money`)
+    /// - Panics if the amount or currency differs from the expected one
+    fn money_equals(&mut self, amount: i64, currency: &str) -> &mut Self;
+
+    /// Creates an assertion scoped to the value of a property.
+    ///
+    /// The new assertion's path is `<path>.<name>`, so the property's value can
+    /// be asserted on without repeating the full path.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::{JsonTest, PropertyAssertions};
+    /// # use serde_json::json;
+    /// # let data = json!({"user": {"name": "John", "settings": {"theme": "dark"}}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.user")
+    ///     .property("settings")
+    ///     .has_property("theme")
+    ///     .property("theme")
+    ///     .equals(json!("dark"));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the value is not an object
+    /// - Panics if the property doesn't exist
+    fn property(&mut self, name: &str) -> JsonPathAssertion<'a>;
+
     /// Creates a PropertyMatcher for testing properties that match a predicate.
     ///
     /// # Examples
@@ -136,21 +340,44 @@ pub trait PropertyAssertions<'a> {
     /// # use json_test::{JsonTest, PropertyAssertions};
     /// # use serde_json::json;
     /// # let data = json!({"user": {"meta_created": "2024-01-01", "meta_updated": "2024-01-02"}});
-    /// # let mut test = JsonTest::new(&data);
+    /// # let test = JsonTest::new(&data);
     /// test.assert_path("$.user")
     ///     .properties_matching(|key| key.starts_with("meta_"))
     ///     .count(2)
     ///     .and()
     ///     .has_property_count(2);
     /// ```
-    fn properties_matching<F>(&'a mut self, predicate: F) -> PropertyMatcher<'a>
+    fn properties_matching<F>(&mut self, predicate: F) -> PropertyMatcher<'_, 'a>
     where
         F: Fn(&str) -> bool;
+
+    /// Creates a PropertyMatcher for testing properties whose names match a regular expression.
+    ///
+    /// The pattern is compiled once and matched against each key with
+    /// `Regex::is_match`, so it is not anchored unless it uses `^` and `$`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::{JsonTest, PropertyAssertions};
+    /// # use serde_json::json;
+    /// # let data = json!({"config": {"key_alpha": 1, "key_beta": 2, "version": 3}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.config")
+    ///     .properties_matching_pattern(r"^key_[a-z]+$")
+    ///     .count(2);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the pattern is invalid
+    /// - Panics if the value is not an object
+    fn properties_matching_pattern(&mut self, pattern: &str) -> PropertyMatcher<'_, 'a>;
 }
 
-impl<'a> PropertyAssertions<'a> for super::base::JsonPathAssertion<'a> {
-    fn has_property(&'a mut self, name: &str) -> &'a mut Self {
-        let obj = self.assert_object();
+impl<'a> PropertyAssertions<'a> for JsonPathAssertion<'a> {
+    fn has_property(&mut self, name: &str) -> &mut Self {
+        let obj = ok_or_fail!(self, self.object_or_error());
 
         if !obj.contains_key(name) {
             let available = obj.keys()
@@ -158,7 +385,7 @@ impl<'a> PropertyAssertions<'a> for super::base::JsonPathAssertion<'a> {
                 .collect::<Vec<_>>()
                 .join(", ");
 
-            panic!("Property '{}' not found at {}\nAvailable properties: {}",
+            fail!(self, "Property '{}' not found at {}\nAvailable properties: {}",
                    name, self.path_str, available);
         }
         self
@@ -169,7 +396,7 @@ impl<'a> PropertyAssertions<'a> for super::base::JsonPathAssertion<'a> {
         I: IntoIterator<Item = S>,
         S: AsRef<str>,
     {
-        let obj = self.assert_object();
+        let obj = ok_or_fail!(self, self.object_or_error());
         let missing: Vec<String> = names.into_iter()
             .filter(|name| !obj.contains_key(name.as_ref()))
             .map(|name| name.as_ref().to_string())
@@ -181,14 +408,56 @@ impl<'a> PropertyAssertions<'a> for super::base::JsonPathAssertion<'a> {
                 .collect::<Vec<_>>()
                 .join(", ");
 
-            panic!("Missing properties at {}: {}\nAvailable properties: {}",
+            fail!(self, "Missing properties at {}: {}\nAvailable properties: {}",
                    self.path_str, missing.join(", "), available);
         }
         self
     }
 
+    fn does_not_have_property(&'_ mut self, name: &str) -> &'_ mut Self {
+        let obj = ok_or_fail!(self, self.object_or_error());
+
+        if let Some(value) = obj.get(name) {
+            fail!(
+                self,
+                "Property '{}' unexpectedly present at {}\nValue: {}",
+                name, self.path_str, value
+            );
+        }
+        self
+    }
+
+    fn has_exactly_properties<I, S>(&'_ mut self, names: I) -> &'_ mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let obj = ok_or_fail!(self, self.object_or_error());
+        let expected: Vec<String> = names.into_iter()
+            .map(|name| name.as_ref().to_string())
+            .collect();
+
+        let missing: Vec<&str> = expected.iter()
+            .filter(|name| !obj.contains_key(name.as_str()))
+            .map(|name| name.as_str())
+            .collect();
+        let unexpected: Vec<&str> = obj.keys()
+            .filter(|key| !expected.contains(key))
+            .map(|key| key.as_str())
+            .collect();
+
+        if !missing.is_empty() || !unexpected.is_empty() {
+            fail!(
+                self,
+                "Properties at {} do not match exactly\nMissing: {:?}\nUnexpected: {:?}",
+                self.path_str, missing, unexpected
+            );
+        }
+        self
+    }
+
     fn has_property_count(&'_ mut self, expected: usize) -> &'_ mut Self {
-        let obj = self.assert_object();
+        let obj = ok_or_fail!(self, self.object_or_error());
         let actual = obj.len();
 
         if actual != expected {
@@ -197,7 +466,8 @@ impl<'a> PropertyAssertions<'a> for super::base::JsonPathAssertion<'a> {
                 .collect::<Vec<_>>()
                 .join(", ");
 
-            panic!(
+            fail!(
+                self,
                 "Incorrect number of properties at {}\nExpected: {}\nActual: {}\nProperties: {}",
                 self.path_str, expected, actual, properties
             );
@@ -205,18 +475,50 @@ impl<'a> PropertyAssertions<'a> for super::base::JsonPathAssertion<'a> {
         self
     }
 
+    fn has_property_of_type(&'_ mut self, name: &str, ty: &str) -> &'_ mut Self {
+        let matcher = match TypeMatcher::from_name(ty) {
+            Some(matcher) => matcher,
+            None => fail!(self, "Unknown JSON type '{}'", ty),
+        };
+        let obj = ok_or_fail!(self, self.object_or_error());
+
+        match obj.get(name) {
+            Some(value) if matcher.matches(value) => self,
+            Some(value) => {
+                fail!(
+                    self,
+                    "Property '{}' at {} has wrong type\nExpected: {}\nActual: {} ({})",
+                    name, self.path_str, ty, type_name(value), value
+                );
+            },
+            None => {
+                let available = obj.keys()
+                    .map(|s| s.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                fail!(
+                    self,
+                    "Property '{}' not found at {}\nAvailable properties: {}",
+                    name, self.path_str, available
+                );
+            }
+        }
+    }
+
     fn has_property_count_matching<F>(&'_ mut self, predicate: F, expected: usize) -> &'_ mut Self
     where
         F: Fn(&str) -> bool,
     {
-        let obj = self.assert_object();
+        let obj = ok_or_fail!(self, self.object_or_error());
         let matching: Vec<&str> = obj.keys()
             .filter(|k| predicate(k))
             .map(|s| s.as_str())
             .collect();
 
         if matching.len() != expected {
-            panic!(
+            fail!(
+                self,
                 "Incorrect number of matching properties at {}\nExpected: {}\nActual: {}\nMatching properties: {}",
                 self.path_str, expected, matching.len(), matching.join(", ")
             );
@@ -224,13 +526,34 @@ impl<'a> PropertyAssertions<'a> for super::base::JsonPathAssertion<'a> {
         self
     }
 
+    fn has_no_properties_matching<F>(&'_ mut self, predicate: F) -> &'_ mut Self
+    where
+        F: Fn(&str) -> bool,
+    {
+        let obj = ok_or_fail!(self, self.object_or_error());
+        let matching: Vec<&str> = obj.keys()
+            .filter(|k| predicate(k))
+            .map(|s| s.as_str())
+            .collect();
+
+        if !matching.is_empty() {
+            fail!(
+                self,
+                "Object at {} unexpectedly has properties matching predicate: {:?}",
+                self.path_str, matching
+            );
+        }
+        self
+    }
+
     fn has_property_value(&'_ mut self, name: &str, expected: Value) -> &'_ mut Self {
-        let obj = self.assert_object();
+        let obj = ok_or_fail!(self, self.object_or_error());
 
         match obj.get(name) {
             Some(actual) if actual == &expected => self,
             Some(actual) => {
-                panic!(
+                fail!(
+                    self,
                     "Property '{}' value mismatch at {}\nExpected: {}\nActual: {}",
                     name, self.path_str, expected, actual
                 );
@@ -241,7 +564,35 @@ impl<'a> PropertyAssertions<'a> for super::base::JsonPathAssertion<'a> {
                     .collect::<Vec<_>>()
                     .join(", ");
 
-                panic!(
+                fail!(
+                    self,
+                    "Property '{}' not found at {}\nAvailable properties: {}",
+                    name, self.path_str, available
+                );
+            }
+        }
+    }
+
+    fn has_property_value_one_of(&'_ mut self, name: &str, allowed: &[Value]) -> &'_ mut Self {
+        let obj = ok_or_fail!(self, self.object_or_error());
+
+        match obj.get(name) {
+            Some(actual) if allowed.contains(actual) => self,
+            Some(actual) => {
+                fail!(
+                    self,
+                    "Property '{}' at {} value {} is not one of {:?}",
+                    name, self.path_str, actual, allowed
+                );
+            },
+            None => {
+                let available = obj.keys()
+                    .map(|s| s.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                fail!(
+                    self,
                     "Property '{}' not found at {}\nAvailable properties: {}",
                     name, self.path_str, available
                 );
@@ -253,12 +604,13 @@ impl<'a> PropertyAssertions<'a> for super::base::JsonPathAssertion<'a> {
     where
         F: Fn(&Value) -> bool,
     {
-        let obj = self.assert_object();
+        let obj = ok_or_fail!(self, self.object_or_error());
 
         match obj.get(name) {
             Some(value) if predicate(value) => self,
             Some(value) => {
-                panic!(
+                fail!(
+                    self,
                     "Property '{}' at {} does not match condition\nValue: {}",
                     name, self.path_str, value
                 );
@@ -269,7 +621,8 @@ impl<'a> PropertyAssertions<'a> for super::base::JsonPathAssertion<'a> {
                     .collect::<Vec<_>>()
                     .join(", ");
 
-                panic!(
+                fail!(
+                    self,
                     "Property '{}' not found at {}\nAvailable properties: {}",
                     name, self.path_str, available
                 );
@@ -277,7 +630,70 @@ impl<'a> PropertyAssertions<'a> for super::base::JsonPathAssertion<'a> {
         }
     }
 
-    fn properties_matching<F>(&'a mut self, predicate: F) -> PropertyMatcher<'a>
+    fn values_are_unique(&'_ mut self) -> &'_ mut Self {
+        let obj = ok_or_fail!(self, self.object_or_error());
+        let mut seen: HashMap<String, &str> = HashMap::new();
+
+        for (key, value) in &obj {
+            let serialized = value.to_string();
+            if let Some(first) = seen.get(&serialized) {
+                fail!(
+                    self,
+                    "Duplicate value {} for keys '{}' and '{}' at {}",
+                    serialized, first, key, self.path_str
+                );
+            }
+            seen.insert(serialized, key);
+        }
+        self
+    }
+
+    fn is_money(&'_ mut self) -> &'_ mut Self {
+        let obj = ok_or_fail!(self, self.object_or_error());
+        if let Err(message) = money_parts(&obj, &self.path_str) {
+            fail!(self, "{}", message);
+        }
+        self
+    }
+
+    fn money_equals(&'_ mut self, amount: i64, currency: &str) -> &'_ mut Self {
+        let obj = ok_or_fail!(self, self.object_or_error());
+        let (actual_amount, actual_currency) = match money_parts(&obj, &self.path_str) {
+            Ok(parts) => parts,
+            Err(message) => fail!(self, "{}", message),
+        };
+
+        if actual_amount != amount || actual_currency != currency {
+            fail!(
+                self,
+                "Money at {} does not match\nExpected: {} {}\nActual: {} {}",
+                self.path_str, amount, currency, actual_amount, actual_currency
+            );
+        }
+        self
+    }
+
+    fn property(&mut self, name: &str) -> JsonPathAssertion<'a> {
+        match self.object_or_error() {
+            Ok(obj) if !obj.contains_key(name) => {
+                let available = obj.keys()
+                    .map(|s| s.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                self.report(format!(
+                    "Property '{}' not found at {}\nAvailable properties: {}",
+                    name, self.path_str, available
+                ));
+            }
+            Ok(_) => {}
+            Err(error) => self.report_error(error),
+        }
+
+        self.child(format!("{}.{}", self.path_str, name), |value| value.get(name))
+    }
+
+    fn properties_matching<F>(&mut self, predicate: F) -> PropertyMatcher<'_, 'a>
     where
         F: Fn(&str) -> bool,
     {
@@ -289,12 +705,36 @@ impl<'a> PropertyAssertions<'a> for super::base::JsonPathAssertion<'a> {
 
         PropertyMatcher::new(pairs, self)
     }
+
+    fn properties_matching_pattern(&mut self, pattern: &str) -> PropertyMatcher<'_, 'a> {
+        let regex = regex::Regex::new(pattern)
+            .unwrap_or_else(|e| panic!("Invalid regex pattern: {}", e));
+
+        self.properties_matching(|key| regex.is_match(key))
+    }
+}
+
+/// Extracts the amount and currency of a money object, reporting which part is invalid.
+fn money_parts<'v>(obj: &'v serde_json::Map<String, Value>, path: &str) -> Result<(i64, &'v str), String> {
+    let amount = match obj.get("amount") {
+        Some(Value::Number(n)) if n.is_i64() => n.as_i64().unwrap_or_default(),
+        Some(v) => return Err(format!("Money at {} has invalid amount: expected integer, got {}", path, v)),
+        None => return Err(format!("Money at {} is missing property 'amount'", path)),
+    };
+    let currency = match obj.get("currency") {
+        Some(Value::String(s)) if s.len() == 3 && s.bytes().all(|b| b.is_ascii_uppercase()) => s.as_str(),
+        Some(v) => return Err(format!(
+            "Money at {} has invalid currency: expected three-letter uppercase code, got {}",
+            path, v
+        )),
+        None => return Err(format!("Money at {} is missing property 'currency'", path)),
+    };
+    Ok((amount, currency))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::assertions::base::JsonPathAssertion;
     use serde_json::json;
 
     #[test]
@@ -344,4 +784,378 @@ mod tests {
         let mut assertion = JsonPathAssertion::new_for_test(&json, "$.user");
         assertion.has_property_value("age", json!(25));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_matching_count_bounds() {
+        let json = json!({"keys": {"prod_eu": "k1", "prod_us": "k2", "dev": "k3"}});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.keys");
+        assertion
+            .properties_matching(|k| k.starts_with("prod_"))
+            .count_at_least(2)
+            .count_at_most(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected at least 1 matching properties but found 0 at $.keys")]
+    fn test_matching_count_at_least_fails() {
+        let json = json!({"keys": {"dev": "k3"}});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.keys");
+        assertion
+            .properties_matching(|k| k.starts_with("prod_"))
+            .count_at_least(1);
+    }
+
+    #[test]
+    fn test_has_no_properties_matching() {
+        let json = json!({"user": {"name": "John", "email": "john@example.com"}});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.user");
+        assertion.has_no_properties_matching(|k| k.starts_with("_"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Object at $.user unexpectedly has properties matching predicate: [\"_tmp\"]")]
+    fn test_has_no_properties_matching_fails() {
+        let json = json!({"user": {"name": "John", "_tmp": 1}});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.user");
+        assertion.has_no_properties_matching(|k| k.starts_with("_"));
+    }
+
+    #[test]
+    fn test_money() {
+        let json = json!({"total": {"amount": 1299, "currency": "USD"}});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.total");
+        assertion.is_money().money_equals(1299, "USD");
+    }
+
+    #[test]
+    #[should_panic(expected = "Money at $.total has invalid amount: expected integer, got 12.99")]
+    fn test_money_fractional_amount() {
+        let json = json!({"total": {"amount": 12.99, "currency": "USD"}});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.total");
+        assertion.is_money();
+    }
+
+    #[test]
+    #[should_panic(expected = "Money at $.total has invalid currency: expected three-letter uppercase code, got \"usd\"")]
+    fn test_money_lowercase_currency() {
+        let json = json!({"total": {"amount": 1299, "currency": "usd"}});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.total");
+        assertion.is_money();
+    }
+
+    #[test]
+    #[should_panic(expected = "Money at $.total does not match\nExpected: 1299 EUR\nActual: 1299 USD")]
+    fn test_money_equals_currency_mismatch() {
+        let json = json!({"total": {"amount": 1299, "currency": "USD"}});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.total");
+        assertion.money_equals(1299, "EUR");
+    }
+
+    #[test]
+    fn test_has_property_value_one_of() {
+        let json = json!({"order": {"status": "shipped"}});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.order");
+        assertion.has_property_value_one_of("status", &[json!("pending"), json!("shipped")]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Property 'status' at $.order value \"lost\" is not one of [String(\"pending\"), String(\"shipped\")]")]
+    fn test_has_property_value_one_of_fails() {
+        let json = json!({"order": {"status": "lost"}});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.order");
+        assertion.has_property_value_one_of("status", &[json!("pending"), json!("shipped")]);
+    }
+
+    #[test]
+    fn test_values_are_unique() {
+        let json = json!({"codes": {"active": "A", "inactive": "I", "flags": [1], "other": [2]}});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.codes");
+        assertion.values_are_unique();
+    }
+
+    #[test]
+    #[should_panic(expected = "Duplicate value \"A\" for keys 'active' and 'archived' at $.codes")]
+    fn test_values_are_unique_duplicate() {
+        let json = json!({"codes": {"active": "A", "inactive": "I", "archived": "A"}});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.codes");
+        assertion.values_are_unique();
+    }
+
+    #[test]
+    fn test_property_dive() {
+        let json = json!({"user": {"settings": {"theme": "dark", "layout": {"columns": 2}}}});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.user");
+        let mut settings = assertion.property("settings");
+        assert_eq!(settings.path_str, "$.user.settings");
+        settings
+            .has_property("theme")
+            .property("layout")
+            .property("columns")
+            .equals(json!(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "Property 'settings' not found at $.user\nAvailable properties: name")]
+    fn test_property_missing() {
+        let json = json!({"user": {"name": "John"}});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.user");
+        assertion.property("settings");
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected object at $.user.settings")]
+    fn test_property_of_non_object() {
+        let json = json!({"user": {"settings": "dark"}});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.user");
+        assertion.property("settings").property("theme");
+    }
+
+    #[test]
+    fn test_does_not_have_property() {
+        let json = json!({"user": {"name": "John", "email": "john@example.com"}});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.user");
+        assertion
+            .does_not_have_property("password")
+            .does_not_have_property("Name")
+            .has_property("name");
+    }
+
+    #[test]
+    #[should_panic(expected = "Property 'password' unexpectedly present at $.user\nValue: \"hunter2\"")]
+    fn test_does_not_have_property_present() {
+        let json = json!({"user": {"name": "John", "password": "hunter2"}});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.user");
+        assertion.does_not_have_property("password");
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected object at $.user")]
+    fn test_does_not_have_property_non_object() {
+        let json = json!({"user": ["password"]});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.user");
+        assertion.does_not_have_property("password");
+    }
+
+    #[test]
+    fn test_has_exactly_properties() {
+        let json = json!({"user": {"id": 1, "name": "John"}, "empty": {}});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.user");
+        assertion
+            .has_exactly_properties(["name", "id"])
+            .has_exactly_properties(vec!["id".to_string(), "name".to_string(), "id".to_string()]);
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.empty");
+        assertion.has_exactly_properties(Vec::<&str>::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "Properties at $.user do not match exactly\nMissing: [\"email\"]\nUnexpected: []")]
+    fn test_has_exactly_properties_missing() {
+        let json = json!({"user": {"id": 1, "name": "John"}});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.user");
+        assertion.has_exactly_properties(["id", "name", "email"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Properties at $.user do not match exactly\nMissing: [\"email\"]\nUnexpected: [\"password\"]")]
+    fn test_has_exactly_properties_extra() {
+        let json = json!({"user": {"id": 1, "password": "hunter2"}});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.user");
+        assertion.has_exactly_properties(["id", "email"]);
+    }
+
+    #[test]
+    fn test_has_property_of_type() {
+        let json = json!({"record": {
+            "name": "John",
+            "age": 30,
+            "active": true,
+            "deleted_at": null,
+            "tags": ["a"],
+            "address": {"city": "Paris"}
+        }});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.record");
+        assertion
+            .has_property_of_type("name", "string")
+            .has_property_of_type("age", "number")
+            .has_property_of_type("active", "boolean")
+            .has_property_of_type("deleted_at", "null")
+            .has_property_of_type("tags", "array")
+            .has_property_of_type("address", "object");
+    }
+
+    #[test]
+    #[should_panic(expected = "Property 'age' at $.user has wrong type\nExpected: number\nActual: string (\"30\")")]
+    fn test_has_property_of_type_mismatch() {
+        let json = json!({"user": {"age": "30"}});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.user");
+        assertion.has_property_of_type("age", "number");
+    }
+
+    #[test]
+    #[should_panic(expected = "Property 'age' not found at $.user")]
+    fn test_has_property_of_type_missing() {
+        let json = json!({"user": {"name": "John"}});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.user");
+        assertion.has_property_of_type("age", "number");
+    }
+
+    #[test]
+    #[should_panic(expected = "Unknown JSON type 'integer'")]
+    fn test_has_property_of_type_unknown_type() {
+        let json = json!({"user": {"age": 30}});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.user");
+        assertion.has_property_of_type("age", "integer");
+    }
+
+    #[test]
+    fn test_properties_matching_pattern() {
+        let json = json!({"config": {"key_alpha": 1, "key_beta": 2, "key_3": 3, "version": 4}});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.config");
+        assertion
+            .properties_matching_pattern(r"^key_[a-z]+$")
+            .count(2)
+            .and()
+            .properties_matching_pattern(r"^meta_")
+            .count(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected 2 matching properties but found 1 at $.config")]
+    fn test_properties_matching_pattern_count_mismatch() {
+        let json = json!({"config": {"key_alpha": 1, "version": 4}});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.config");
+        assertion.properties_matching_pattern("^key_").count(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid regex pattern")]
+    fn test_properties_matching_pattern_invalid() {
+        let json = json!({"config": {"key_alpha": 1}});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.config");
+        assertion.properties_matching_pattern("key_[a-z");
+    }
+
+    #[test]
+    fn test_matcher_values_all() {
+        let json = json!({"config": {"debug_level": 3, "debug_retries": 1, "name": "svc"}});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.config");
+        assertion
+            .properties_matching(|key| key.starts_with("debug_"))
+            .values_all(|v| v.as_i64().is_some_and(|n| n > 0))
+            .values_count_matching(|v| v == &json!(3), 1)
+            .values_count_matching(Value::is_string, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value of property 'debug_retries' did not match predicate at $.config\nActual: -1")]
+    fn test_matcher_values_all_fails() {
+        let json = json!({"config": {"debug_level": 3, "debug_retries": -1, "name": "svc"}});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.config");
+        assertion
+            .properties_matching(|key| key.starts_with("debug_"))
+            .values_all(|v| v.as_i64().is_some_and(|n| n > 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected 2 property values matching predicate but found 1 at $.config\nMatching properties: debug_level")]
+    fn test_matcher_values_count_matching_fails() {
+        let json = json!({"config": {"debug_level": 3, "debug_verbose": true}});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.config");
+        assertion
+            .properties_matching(|key| key.starts_with("debug_"))
+            .values_count_matching(Value::is_number, 2);
+    }
+
+    #[test]
+    fn test_matcher_each() {
+        let json = json!({"services": {
+            "svc_api": {"host": "api.local", "port": 8080},
+            "svc_db": {"host": "db.local", "port": 5432},
+            "version": 2
+        }});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.services");
+        assertion
+            .properties_matching(|key| key.starts_with("svc_"))
+            .each(|service| {
+                service.is_object()
+                    .has_properties(["host", "port"])
+                    .property("port")
+                    .is_between(1, 65535);
+            })
+            .count(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Property 'port' not found at $.services.svc_db\nAvailable properties: host")]
+    fn test_matcher_each_names_failing_key() {
+        let json = json!({"services": {
+            "svc_api": {"host": "api.local", "port": 8080},
+            "svc_db": {"host": "db.local"}
+        }});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.services");
+        assertion
+            .properties_matching(|key| key.starts_with("svc_"))
+            .each(|service| {
+                service.has_property("port");
+            });
+    }
+
+    #[test]
+    fn test_matcher_collect_sorted() {
+        let mut object = serde_json::Map::new();
+        for key in ["tag_zeta", "tag_alpha", "other", "tag_Beta", "tag_mid"] {
+            object.insert(key.to_string(), json!(key.len()));
+        }
+        let json = json!({"tags": object});
+
+        let keys = JsonPathAssertion::new_for_test(&json, "$.tags")
+            .properties_matching(|key| key.starts_with("tag_"))
+            .collect_keys_sorted();
+        assert_eq!(keys, ["tag_Beta", "tag_alpha", "tag_mid", "tag_zeta"]);
+
+        let pairs = JsonPathAssertion::new_for_test(&json, "$.tags")
+            .properties_matching(|key| key.starts_with("tag_"))
+            .collect_pairs_sorted();
+        assert_eq!(pairs, vec![
+            ("tag_Beta".to_string(), json!(8)),
+            ("tag_alpha".to_string(), json!(9)),
+            ("tag_mid".to_string(), json!(7)),
+            ("tag_zeta".to_string(), json!(8)),
+        ]);
+    }
+
+    #[test]
+    fn test_matcher_all_values_of_type() {
+        let json = json!({"flags": {"debug_mode": false, "beta_features": true, "dark_mode": true, "max_users": 10}});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.flags");
+        assertion
+            .properties_matching(|key| key.ends_with("_mode") || key.ends_with("_features"))
+            .all_values_of_type("boolean")
+            .count_of_type("boolean", 3)
+            .count_of_type("number", 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Property 'beta_features' at $.flags has wrong type\nExpected: boolean\nActual: string (\"yes\")")]
+    fn test_matcher_all_values_of_type_mixed() {
+        let json = json!({"flags": {"debug_mode": false, "beta_features": "yes", "dark_mode": 1}});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.flags");
+        assertion.properties_matching(|_| true).all_values_of_type("boolean");
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected 2 properties of type boolean but found 1 at $.flags\nMatching properties: debug_mode")]
+    fn test_matcher_count_of_type_mismatch() {
+        let json = json!({"flags": {"debug_mode": false, "beta_features": "yes"}});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.flags");
+        assertion.properties_matching(|_| true).count_of_type("boolean", 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Unknown JSON type 'bool'")]
+    fn test_matcher_all_values_of_unknown_type() {
+        let json = json!({"flags": {"debug_mode": false}});
+        let mut assertion = JsonPathAssertion::new_for_test(&json, "$.flags");
+        assertion.properties_matching(|_| true).all_values_of_type("bool");
+    }
+}
@@ -1,4 +1,8 @@
+use super::base::JsonPathAssertion;
+use crate::error::type_name;
+use crate::matchers::{JsonMatcher, TypeMatcher};
 use serde_json::Value;
+use std::borrow::Cow;
 
 /// Matches and collects properties based on custom predicates.
 ///
@@ -17,20 +21,20 @@ use serde_json::Value;
 /// #         "meta_updated": "2024-01-02"
 /// #     }
 /// # });
-/// # let mut test = JsonTest::new(&data);
+/// # let test = JsonTest::new(&data);
 /// test.assert_path("$.user")
 ///     .properties_matching(|key| key.starts_with("meta_"))
 ///     .count(2)
 ///     .and()
 ///     .has_property("name");
 /// ```
-pub struct PropertyMatcher<'a> {
+pub struct PropertyMatcher<'s, 'a> {
     pairs: Vec<(String, Value)>,
-    assertion: &'a mut super::base::JsonPathAssertion<'a>,
+    assertion: &'s mut JsonPathAssertion<'a>,
 }
 
-impl<'a> PropertyMatcher<'a> {
-    pub(crate) fn new(pairs: Vec<(String, Value)>, assertion: &'a mut super::base::JsonPathAssertion<'a>) -> Self {
+impl<'s, 'a> PropertyMatcher<'s, 'a> {
+    pub(crate) fn new(pairs: Vec<(String, Value)>, assertion: &'s mut JsonPathAssertion<'a>) -> Self {
         Self { pairs, assertion }
     }
 
@@ -42,7 +46,7 @@ impl<'a> PropertyMatcher<'a> {
     /// # use json_test::{JsonTest, PropertyAssertions};
     /// # use serde_json::json;
     /// # let data = json!({"user": {"meta_created": "2024-01-01", "meta_updated": "2024-01-02"}});
-    /// # let mut test = JsonTest::new(&data);
+    /// # let test = JsonTest::new(&data);
     /// test.assert_path("$.user")
     ///     .properties_matching(|key| key.starts_with("meta_"))
     ///     .count(2);
@@ -52,14 +56,72 @@ impl<'a> PropertyMatcher<'a> {
     ///
     /// Panics if the number of matching properties doesn't equal the expected count.
     pub fn count(self, expected: usize) -> Self {
-        assert_eq!(
-            self.pairs.len(),
-            expected,
-            "Expected {} matching properties but found {} at {}",
-            expected,
-            self.pairs.len(),
-            self.assertion.path_str
-        );
+        if self.pairs.len() != expected {
+            self.assertion.report(format!(
+                "Expected {} matching properties but found {} at {}",
+                expected,
+                self.pairs.len(),
+                self.assertion.path_str
+            ));
+        }
+        self
+    }
+
+    /// Asserts that at least `min` properties match.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::{JsonTest, PropertyAssertions};
+    /// # use serde_json::json;
+    /// # let data = json!({"keys": {"prod_eu": "k1", "prod_us": "k2", "dev": "k3"}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.keys")
+    ///     .properties_matching(|key| key.starts_with("prod_"))
+    ///     .count_at_least(1);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if fewer than `min` properties match.
+    pub fn count_at_least(self, min: usize) -> Self {
+        if self.pairs.len() < min {
+            self.assertion.report(format!(
+                "Expected at least {} matching properties but found {} at {}",
+                min,
+                self.pairs.len(),
+                self.assertion.path_str
+            ));
+        }
+        self
+    }
+
+    /// Asserts that at most `max` properties match.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::{JsonTest, PropertyAssertions};
+    /// # use serde_json::json;
+    /// # let data = json!({"keys": {"prod_eu": "k1", "prod_us": "k2", "dev": "k3"}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.keys")
+    ///     .properties_matching(|key| key.starts_with("dev"))
+    ///     .count_at_most(1);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if more than `max` properties match.
+    pub fn count_at_most(self, max: usize) -> Self {
+        if self.pairs.len() > max {
+            self.assertion.report(format!(
+                "Expected at most {} matching properties but found {} at {}",
+                max,
+                self.pairs.len(),
+                self.assertion.path_str
+            ));
+        }
         self
     }
 
@@ -71,7 +133,7 @@ impl<'a> PropertyMatcher<'a> {
     /// # use json_test::{JsonTest, PropertyAssertions};
     /// # use serde_json::json;
     /// # let data = json!({"config": {"debug_mode": true, "debug_level": 3}});
-    /// # let mut test = JsonTest::new(&data);
+    /// # let test = JsonTest::new(&data);
     /// test.assert_path("$.config")
     ///     .properties_matching(|key| key.starts_with("debug_"))
     ///     .all(|(key, _)| key.len() > 5);
@@ -84,13 +146,215 @@ impl<'a> PropertyMatcher<'a> {
     where
         F: Fn((&str, &Value)) -> bool
     {
-        for (k, v) in &self.pairs {
-            assert!(
-                predicate((k, v)),
+        if let Some((k, v)) = self.pairs.iter().find(|(k, v)| !predicate((k, v))) {
+            self.assertion.report(format!(
                 "Property {:?} did not match predicate at {}",
                 (k, v),
                 self.assertion.path_str
-            );
+            ));
+        }
+        self
+    }
+
+    /// Asserts that the values of all matching properties satisfy a predicate.
+    ///
+    /// Unlike `all`, the predicate only receives the value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::{JsonTest, PropertyAssertions};
+    /// # use serde_json::json;
+    /// # let data = json!({"limits": {"max_users": 10, "max_projects": 5, "name": "pro"}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.limits")
+    ///     .properties_matching(|key| key.starts_with("max_"))
+    ///     .values_all(|value| value.as_i64().is_some_and(|n| n > 0));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value of any matching property fails to satisfy the predicate.
+    pub fn values_all<F>(self, predicate: F) -> Self
+    where
+        F: Fn(&Value) -> bool
+    {
+        if let Some((k, v)) = self.pairs.iter().find(|(_, v)| !predicate(v)) {
+            self.assertion.report(format!(
+                "Value of property '{}' did not match predicate at {}\nActual: {}",
+                k,
+                self.assertion.path_str,
+                v
+            ));
+        }
+        self
+    }
+
+    /// Asserts that the expected number of matching properties have values satisfying a predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::{JsonTest, PropertyAssertions};
+    /// # use serde_json::json;
+    /// # let data = json!({"features": {"flag_beta": true, "flag_dark": false, "flag_new": true}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.features")
+    ///     .properties_matching(|key| key.starts_with("flag_"))
+    ///     .values_count_matching(|value| value == &json!(true), 2);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of matching values doesn't equal the expected count.
+    pub fn values_count_matching<F>(self, predicate: F, expected: usize) -> Self
+    where
+        F: Fn(&Value) -> bool
+    {
+        let matching: Vec<&str> = self.pairs.iter()
+            .filter(|(_, v)| predicate(v))
+            .map(|(k, _)| k.as_str())
+            .collect();
+
+        if matching.len() != expected {
+            self.assertion.report(format!(
+                "Expected {} property values matching predicate but found {} at {}\nMatching properties: {}",
+                expected,
+                matching.len(),
+                self.assertion.path_str,
+                matching.join(", ")
+            ));
+        }
+        self
+    }
+
+    /// Asserts that the values of all matching properties have the JSON type `ty`.
+    ///
+    /// `ty` is one of `"string"`, `"number"`, `"boolean"`, `"null"`, `"array"`
+    /// or `"object"`, as accepted by `TypeMatcher`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::{JsonTest, PropertyAssertions};
+    /// # use serde_json::json;
+    /// # let data = json!({"features": {"flag_beta": true, "flag_dark": false, "rollout": 0.5}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.features")
+    ///     .properties_matching(|key| key.starts_with("flag_"))
+    ///     .all_values_of_type("boolean");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `ty` is not a JSON type name
+    /// - Panics on the first matching property whose value has another type,
+    ///   naming the property and its value
+    pub fn all_values_of_type(self, ty: &str) -> Self {
+        let matcher = match TypeMatcher::from_name(ty) {
+            Some(matcher) => matcher,
+            None => {
+                self.assertion.report(format!("Unknown JSON type '{}'", ty));
+                return self;
+            }
+        };
+
+        if let Some((k, v)) = self.pairs.iter().find(|(_, v)| !matcher.matches(v)) {
+            self.assertion.report(format!(
+                "Property '{}' at {} has wrong type\nExpected: {}\nActual: {} ({})",
+                k,
+                self.assertion.path_str,
+                ty,
+                type_name(v),
+                v
+            ));
+        }
+        self
+    }
+
+    /// Asserts that exactly `expected` matching properties have values of the JSON type `ty`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::{JsonTest, PropertyAssertions};
+    /// # use serde_json::json;
+    /// # let data = json!({"settings": {"debug": false, "beta": true, "theme": "dark"}});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.settings")
+    ///     .properties_matching(|_| true)
+    ///     .count_of_type("boolean", 2)
+    ///     .count_of_type("string", 1);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `ty` is not a JSON type name
+    /// - Panics if the number of values of that type doesn't equal `expected`
+    pub fn count_of_type(self, ty: &str, expected: usize) -> Self {
+        let matcher = match TypeMatcher::from_name(ty) {
+            Some(matcher) => matcher,
+            None => {
+                self.assertion.report(format!("Unknown JSON type '{}'", ty));
+                return self;
+            }
+        };
+
+        let matching: Vec<&str> = self.pairs.iter()
+            .filter(|(_, v)| matcher.matches(v))
+            .map(|(k, _)| k.as_str())
+            .collect();
+
+        if matching.len() != expected {
+            self.assertion.report(format!(
+                "Expected {} properties of type {} but found {} at {}\nMatching properties: {}",
+                expected,
+                ty,
+                matching.len(),
+                self.assertion.path_str,
+                matching.join(", ")
+            ));
+        }
+        self
+    }
+
+    /// Runs assertions against the value of each matching property.
+    ///
+    /// `f` receives a `JsonPathAssertion` scoped to one property's value, with
+    /// the path `<path>.<key>`, so failures name the property that failed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::{JsonTest, PropertyAssertions};
+    /// # use serde_json::json;
+    /// # let data = json!({"services": {
+    /// #     "svc_api": {"host": "api.local", "port": 8080},
+    /// #     "svc_db": {"host": "db.local", "port": 5432}
+    /// # }});
+    /// # let test = JsonTest::new(&data);
+    /// test.assert_path("$.services")
+    ///     .properties_matching(|key| key.starts_with("svc_"))
+    ///     .each(|service| {
+    ///         service.has_properties(["host", "port"])
+    ///             .has_property_of_type("port", "number");
+    ///     });
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if an assertion made by `f` fails for any matching property.
+    pub fn each<F>(self, f: F) -> Self
+    where
+        F: for<'v> Fn(&'v mut JsonPathAssertion<'v>),
+    {
+        for (key, value) in &self.pairs {
+            let mut single = JsonPathAssertion {
+                path_str: format!("{}.{}", self.assertion.path_str, key),
+                current_values: vec![Cow::Borrowed(value)],
+                test: self.assertion.test,
+            };
+            f(&mut single);
         }
         self
     }
@@ -103,7 +367,7 @@ impl<'a> PropertyMatcher<'a> {
     /// # use json_test::{JsonTest, PropertyAssertions};
     /// # use serde_json::json;
     /// # let data = json!({"user": {"meta_created": "2024-01-01", "meta_updated": "2024-01-02"}});
-    /// # let mut test = JsonTest::new(&data);
+    /// # let test = JsonTest::new(&data);
     /// let meta_values = test.assert_path("$.user")
     ///     .properties_matching(|key| key.starts_with("meta_"))
     ///     .collect_values();
@@ -120,7 +384,7 @@ impl<'a> PropertyMatcher<'a> {
     /// # use json_test::{JsonTest, PropertyAssertions};
     /// # use serde_json::json;
     /// # let data = json!({"user": {"meta_created": "2024-01-01", "meta_updated": "2024-01-02"}});
-    /// # let mut test = JsonTest::new(&data);
+    /// # let test = JsonTest::new(&data);
     /// let meta_keys = test.assert_path("$.user")
     ///     .properties_matching(|key| key.starts_with("meta_"))
     ///     .collect_keys();
@@ -138,7 +402,7 @@ impl<'a> PropertyMatcher<'a> {
     /// # use json_test::{JsonTest, PropertyAssertions};
     /// # use serde_json::json;
     /// # let data = json!({"user": {"meta_created": "2024-01-01", "meta_updated": "2024-01-02"}});
-    /// # let mut test = JsonTest::new(&data);
+    /// # let test = JsonTest::new(&data);
     /// let meta_pairs = test.assert_path("$.user")
     ///     .properties_matching(|key| key.starts_with("meta_"))
     ///     .collect_pairs();
@@ -148,6 +412,55 @@ impl<'a> PropertyMatcher<'a> {
         self.pairs
     }
 
+    /// Collects matching property keys into a vector sorted by key.
+    ///
+    /// Unlike `collect_keys`, the order does not depend on how the
+    /// underlying object stores its properties: keys are always returned in
+    /// ascending byte-wise order, so the result can be compared directly with
+    /// `assert_eq!`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::{JsonTest, PropertyAssertions};
+    /// # use serde_json::json;
+    /// # let data = json!({"user": {"meta_updated": "2024-01-02", "meta_created": "2024-01-01"}});
+    /// # let test = JsonTest::new(&data);
+    /// let meta_keys = test.assert_path("$.user")
+    ///     .properties_matching(|key| key.starts_with("meta_"))
+    ///     .collect_keys_sorted();
+    /// assert_eq!(meta_keys, ["meta_created", "meta_updated"]);
+    /// ```
+    pub fn collect_keys_sorted(self) -> Vec<String> {
+        let mut keys = self.collect_keys();
+        keys.sort();
+        keys
+    }
+
+    /// Collects matching property key-value pairs into a vector sorted by key.
+    ///
+    /// Pairs are returned in ascending byte-wise key order, whatever the
+    /// iteration order of the underlying object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::{JsonTest, PropertyAssertions};
+    /// # use serde_json::json;
+    /// # let data = json!({"limits": {"max_users": 50, "max_projects": 10}});
+    /// # let test = JsonTest::new(&data);
+    /// let limits = test.assert_path("$.limits")
+    ///     .properties_matching(|key| key.starts_with("max_"))
+    ///     .collect_pairs_sorted();
+    /// assert_eq!(limits[0], ("max_projects".to_string(), json!(10)));
+    /// assert_eq!(limits[1], ("max_users".to_string(), json!(50)));
+    /// ```
+    pub fn collect_pairs_sorted(self) -> Vec<(String, Value)> {
+        let mut pairs = self.collect_pairs();
+        pairs.sort_by(|(a, _), (b, _)| a.cmp(b));
+        pairs
+    }
+
     /// Returns to the parent assertion for further chaining.
     ///
     /// # Examples
@@ -156,14 +469,14 @@ impl<'a> PropertyMatcher<'a> {
     /// # use json_test::{JsonTest, PropertyAssertions};
     /// # use serde_json::json;
     /// # let data = json!({"user": {"meta_created": "2024-01-01", "name": "John"}});
-    /// # let mut test = JsonTest::new(&data);
+    /// # let test = JsonTest::new(&data);
     /// test.assert_path("$.user")
     ///     .properties_matching(|key| key.starts_with("meta_"))
     ///     .count(1)
     ///     .and()
     ///     .has_property("name");
     /// ```
-    pub fn and(self) -> &'a mut super::base::JsonPathAssertion<'a> {
+    pub fn and(self) -> &'s mut JsonPathAssertion<'a> {
         self.assertion
     }
 }
\ No newline at end of file
@@ -0,0 +1,110 @@
+use std::ffi::{OsStr, OsString};
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const BOLD_RED: &str = "\x1b[1;31m";
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const CYAN: &str = "\x1b[36m";
+
+/// Looks up an environment variable, like `std::env::var_os`.
+pub(crate) type EnvLookup = fn(&str) -> Option<OsString>;
+
+/// Whether the environment read through `env` permits colored output.
+///
+/// Follows the `NO_COLOR` convention (any non-empty value disables colors)
+/// and `CLICOLOR=0`.
+pub(crate) fn env_allows_colors(env: EnvLookup) -> bool {
+    allows_colors(env("NO_COLOR").as_deref(), env("CLICOLOR").as_deref())
+}
+
+fn allows_colors(no_color: Option<&OsStr>, clicolor: Option<&OsStr>) -> bool {
+    let no_color = no_color.is_some_and(|value| !value.is_empty());
+    let clicolor_off = clicolor.is_some_and(|value| value == "0");
+    !no_color && !clicolor_off
+}
+
+/// Highlights a failure message for a terminal.
+///
+/// The first line is shown in bold red with `path` in cyan. Following lines
+/// labelled `Expected...:` or `Actual...:` get a bold label and a green or red
+/// value; any other line is left as is.
+pub(crate) fn highlight(message: &str, path: &str) -> String {
+    let mut lines = message.lines();
+    let mut out = match lines.next() {
+        Some(header) => highlight_header(header, path),
+        None => return String::new(),
+    };
+    for line in lines {
+        out.push('\n');
+        out.push_str(&highlight_line(line));
+    }
+    out
+}
+
+fn highlight_header(header: &str, path: &str) -> String {
+    match header.split_once(path).filter(|_| !path.is_empty()) {
+        Some((before, after)) => format!("{}{CYAN}{path}{RESET}{}", paint(BOLD_RED, before), paint(BOLD_RED, after)),
+        None => paint(BOLD_RED, header),
+    }
+}
+
+/// Wraps `text` in `color`, or returns nothing for empty text.
+fn paint(color: &str, text: &str) -> String {
+    if text.is_empty() {
+        String::new()
+    } else {
+        format!("{color}{text}{RESET}")
+    }
+}
+
+fn highlight_line(line: &str) -> String {
+    let color = if line.starts_with("Expected") {
+        GREEN
+    } else if line.starts_with("Actual") {
+        RED
+    } else {
+        return line.to_string();
+    };
+    match line.split_once(": ") {
+        Some((label, value)) => format!("{BOLD}{label}:{RESET} {color}{value}{RESET}"),
+        None => line.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_color_suppresses_colors() {
+        assert!(allows_colors(None, None));
+        assert!(!allows_colors(Some(OsStr::new("1")), None));
+        assert!(!allows_colors(Some(OsStr::new("true")), Some(OsStr::new("1"))));
+        assert!(allows_colors(Some(OsStr::new("")), None));
+        assert!(!allows_colors(None, Some(OsStr::new("0"))));
+        assert!(allows_colors(None, Some(OsStr::new("1"))));
+    }
+
+    #[test]
+    fn test_highlight() {
+        let message = "Value mismatch at $.user.age\nExpected: 25\nActual: 30\nHint: check units";
+        assert_eq!(
+            highlight(message, "$.user.age"),
+            "\x1b[1;31mValue mismatch at \x1b[0m\x1b[36m$.user.age\x1b[0m\n\
+             \x1b[1mExpected:\x1b[0m \x1b[32m25\x1b[0m\n\
+             \x1b[1mActual:\x1b[0m \x1b[31m30\x1b[0m\n\
+             Hint: check units"
+        );
+    }
+
+    #[test]
+    fn test_highlight_header_path_first() {
+        assert_eq!(highlight("$.x is missing", "$.x"), "\x1b[36m$.x\x1b[0m\x1b[1;31m is missing\x1b[0m");
+    }
+
+    #[test]
+    fn test_highlight_header_without_path() {
+        assert_eq!(highlight("Invalid JSON", "$.x"), "\x1b[1;31mInvalid JSON\x1b[0m");
+    }
+}
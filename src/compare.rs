@@ -0,0 +1,167 @@
+use crate::{describe_values, JsonTest};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// Compares two JSON documents path by path.
+///
+/// Created by `JsonTest::compare()`. Each call to `assert_path` resolves the
+/// same JSONPath expression in both documents and returns a `ComparisonAssertion`
+/// over the two results. Failure messages always show both sides.
+///
+/// # Examples
+///
+/// ```rust
+/// # use json_test::JsonTest;
+/// # use serde_json::json;
+/// let v1 = json!({"user": {"id": 7, "name": "John"}});
+/// let v2 = json!({"user": {"id": 7, "name": "John", "avatar": "a.png"}});
+///
+/// let compare = JsonTest::compare(&v1, &v2);
+/// compare.assert_path("$.user.id").values_equal();
+/// compare.assert_path("$.user.name").both_exist().values_equal();
+/// compare.assert_path("$.user.avatar").only_in_second();
+/// ```
+#[derive(Debug)]
+pub struct JsonCompare<'a> {
+    first: JsonTest<'a>,
+    second: JsonTest<'a>,
+}
+
+impl<'a> JsonCompare<'a> {
+    pub(crate) fn new(first: &'a Value, second: &'a Value) -> Self {
+        Self {
+            first: JsonTest::new(first),
+            second: JsonTest::new(second),
+        }
+    }
+
+    /// Resolves `path` in both documents and returns an assertion comparing the results.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the JSONPath expression is invalid.
+    pub fn assert_path(&self, path: &str) -> ComparisonAssertion<'_> {
+        ComparisonAssertion {
+            path_str: path.to_string(),
+            first: self.first.assert_path(path).current_values,
+            second: self.second.assert_path(path).current_values,
+        }
+    }
+}
+
+/// Assertions on the values a path selects in two documents.
+///
+/// Created by `JsonCompare::assert_path()`. Methods return `&mut Self` so
+/// several checks can be chained.
+#[derive(Debug)]
+pub struct ComparisonAssertion<'a> {
+    path_str: String,
+    first: Vec<Cow<'a, Value>>,
+    second: Vec<Cow<'a, Value>>,
+}
+
+impl ComparisonAssertion<'_> {
+    /// Asserts that the path selects the same values in both documents.
+    ///
+    /// A path missing from both documents counts as equal.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the selected values differ.
+    pub fn values_equal(&mut self) -> &mut Self {
+        if self.first != self.second {
+            panic!(
+                "Values at {} differ between documents\nFirst: {}\nSecond: {}",
+                self.path_str,
+                describe_values(&self.first),
+                describe_values(&self.second)
+            );
+        }
+        self
+    }
+
+    /// Asserts that the path exists in both documents.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the path is missing from either document.
+    pub fn both_exist(&mut self) -> &mut Self {
+        if self.first.is_empty() || self.second.is_empty() {
+            panic!(
+                "Path {} does not exist in both documents\nFirst: {}\nSecond: {}",
+                self.path_str,
+                describe_values(&self.first),
+                describe_values(&self.second)
+            );
+        }
+        self
+    }
+
+    /// Asserts that the path exists in the first document but not in the second.
+    ///
+    /// # Panics
+    ///
+    /// Panics unless the path exists only in the first document.
+    pub fn only_in_first(&mut self) -> &mut Self {
+        if self.first.is_empty() || !self.second.is_empty() {
+            panic!(
+                "Path {} should exist only in the first document\nFirst: {}\nSecond: {}",
+                self.path_str,
+                describe_values(&self.first),
+                describe_values(&self.second)
+            );
+        }
+        self
+    }
+
+    /// Asserts that the path exists in the second document but not in the first.
+    ///
+    /// # Panics
+    ///
+    /// Panics unless the path exists only in the second document.
+    pub fn only_in_second(&mut self) -> &mut Self {
+        if !self.first.is_empty() || self.second.is_empty() {
+            panic!(
+                "Path {} should exist only in the second document\nFirst: {}\nSecond: {}",
+                self.path_str,
+                describe_values(&self.first),
+                describe_values(&self.second)
+            );
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_compare_documents() {
+        let v1 = json!({"user": {"id": 7, "legacy": true}});
+        let v2 = json!({"user": {"id": 7, "avatar": "a.png"}});
+        let compare = JsonCompare::new(&v1, &v2);
+
+        compare.assert_path("$.user.id").both_exist().values_equal();
+        compare.assert_path("$.user.legacy").only_in_first();
+        compare.assert_path("$.user.avatar").only_in_second();
+        compare.assert_path("$.user.missing").values_equal();
+    }
+
+    #[test]
+    #[should_panic(expected = "Values at $.user.id differ between documents\nFirst: 7\nSecond: \"7\"")]
+    fn test_compare_values_differ() {
+        let v1 = json!({"user": {"id": 7}});
+        let v2 = json!({"user": {"id": "7"}});
+        JsonCompare::new(&v1, &v2).assert_path("$.user.id").values_equal();
+    }
+
+    #[test]
+    #[should_panic(expected = "Path $.user.avatar does not exist in both documents\nFirst: (missing)\nSecond: \"a.png\"")]
+    fn test_compare_missing_in_first() {
+        let v1 = json!({"user": {}});
+        let v2 = json!({"user": {"avatar": "a.png"}});
+        JsonCompare::new(&v1, &v2).assert_path("$.user.avatar").both_exist();
+    }
+}
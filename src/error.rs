@@ -113,8 +113,81 @@ impl JsonPathError {
     }
 }
 
+impl JsonPathError {
+    /// Formats several failures as one report grouped by path.
+    ///
+    /// The report starts with the number of failing paths and failures. Each
+    /// path is then listed once, in the order it first failed, with its failure
+    /// messages indented below it. Identical failures on the same path are shown
+    /// once. This is the format used when many failures are collected before
+    /// being reported together.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonPathError;
+    /// # use serde_json::{json, Value};
+    /// # use std::collections::HashMap;
+    /// let errors = vec![
+    ///     JsonPathError::value_mismatch("$.user.name".to_string(), json!("John"), json!("Jane")),
+    ///     JsonPathError::type_mismatch("$.user.age".to_string(), json!("30"), "number"),
+    ///     JsonPathError::assertion_failed("Too short", "$.user.name", Value::Null, None, HashMap::new()),
+    /// ];
+    ///
+    /// let report = JsonPathError::grouped_report(&errors);
+    /// assert!(report.starts_with("2 failing paths, 3 failures"));
+    /// ```
+    pub fn grouped_report(errors: &[JsonPathError]) -> String {
+        let mut groups: Vec<(&str, Vec<String>)> = Vec::new();
+        for error in errors {
+            let (path, lines) = match error {
+                JsonPathError::AssertionFailed { message, path, actual, expected, .. } => {
+                    let mut lines: Vec<String> = message.lines().map(str::to_string).collect();
+                    let has_line = |lines: &[String], prefix: &str| lines.iter().any(|l| l.starts_with(prefix));
+                    if let Some(expected) = expected {
+                        if !has_line(&lines, "Expected: ") {
+                            lines.push(format!("Expected: {}", expected));
+                        }
+                    }
+                    if !actual.is_null() && !has_line(&lines, "Actual: ") {
+                        lines.push(format!("Actual: {}", actual));
+                    }
+                    (path.as_str(), lines.join("\n"))
+                }
+                JsonPathError::InvalidPath(path) => (path.as_str(), "Invalid JSONPath expression".to_string()),
+            };
+
+            match groups.iter_mut().find(|(p, _)| *p == path) {
+                Some((_, failures)) if failures.contains(&lines) => {}
+                Some((_, failures)) => failures.push(lines),
+                None => groups.push((path, vec![lines])),
+            }
+        }
+
+        let total: usize = groups.iter().map(|(_, failures)| failures.len()).sum();
+        let mut report = format!(
+            "{} failing path{}, {} failure{}",
+            groups.len(),
+            if groups.len() == 1 { "" } else { "s" },
+            total,
+            if total == 1 { "" } else { "s" }
+        );
+        for (path, failures) in groups {
+            report.push_str("\n\n");
+            report.push_str(path);
+            for failure in failures {
+                for line in failure.lines() {
+                    report.push_str("\n    ");
+                    report.push_str(line);
+                }
+            }
+        }
+        report
+    }
+}
+
 /// Helper function to get readable type names
-fn type_name(value: &Value) -> String {
+pub(crate) fn type_name(value: &Value) -> String {
     match value {
         Value::Null => "null",
         Value::Bool(_) => "boolean",
@@ -126,6 +199,7 @@ fn type_name(value: &Value) -> String {
 }
 
 /// Extension trait for adding context to errors
+#[allow(clippy::result_large_err)]
 pub trait ErrorContext<T> {
     fn with_context<K, V>(self, key: K, value: V) -> Result<T, JsonPathError>
     where
@@ -139,6 +213,7 @@ pub trait ErrorContext<T> {
         V: Into<String>;
 }
 
+#[allow(clippy::result_large_err)]
 impl<T> ErrorContext<T> for Result<T, JsonPathError> {
     fn with_context<K, V>(self, key: K, value: V) -> Result<T, JsonPathError>
     where
@@ -198,4 +273,44 @@ impl<T> ErrorContext<T> for Result<T, JsonPathError> {
             }
         })
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_grouped_report() {
+        let errors = vec![
+            JsonPathError::value_mismatch("$.user.name".to_string(), json!("John"), json!("Jane")),
+            JsonPathError::assertion_failed("Too short", "$.user.age", Value::Null, None, HashMap::new()),
+            JsonPathError::assertion_failed("Not lowercase\nActual: \"John\"", "$.user.name", Value::Null, None, HashMap::new()),
+            JsonPathError::value_mismatch("$.user.name".to_string(), json!("John"), json!("Jane")),
+        ];
+
+        assert_eq!(
+            JsonPathError::grouped_report(&errors),
+            "2 failing paths, 3 failures\n\
+             \n\
+             $.user.name\n    \
+             Value mismatch\n    \
+             Expected: \"Jane\"\n    \
+             Actual: \"John\"\n    \
+             Not lowercase\n    \
+             Actual: \"John\"\n\
+             \n\
+             $.user.age\n    \
+             Too short"
+        );
+    }
+
+    #[test]
+    fn test_grouped_report_single_failure() {
+        let errors = vec![JsonPathError::InvalidPath("$.[".to_string())];
+        assert_eq!(
+            JsonPathError::grouped_report(&errors),
+            "1 failing path, 1 failure\n\n$.[\n    Invalid JSONPath expression"
+        );
+    }
+}
@@ -9,6 +9,7 @@
 //! - **JsonPathAssertion**: Chainable assertions on JSON values
 //! - **PropertyAssertions**: Object property validation
 //! - **Matchers**: Flexible value matching and validation
+//! - **JsonCompare**: The same path asserted across two documents
 //!
 //! # Features
 //!
@@ -36,7 +37,7 @@
 //!     }
 //! });
 //!
-//! let mut test = JsonTest::new(&data);
+//! let test = JsonTest::new(&data);
 //!
 //! // Chain multiple assertions on a single value
 //! test.assert_path("$.user.name")
@@ -51,7 +52,7 @@
 //! # use json_test::JsonTest;
 //! # use serde_json::json;
 //! # let data = json!({"score": 85});
-//! # let mut test = JsonTest::new(&data);
+//! # let test = JsonTest::new(&data);
 //! test.assert_path("$.score")
 //!     .is_number()
 //!     .is_greater_than(80)
@@ -65,7 +66,7 @@
 //! # use json_test::JsonTest;
 //! # use serde_json::json;
 //! # let data = json!({"roles": ["user", "admin"]});
-//! # let mut test = JsonTest::new(&data);
+//! # let test = JsonTest::new(&data);
 //! test.assert_path("$.roles")
 //!     .is_array()
 //!     .has_length(2)
@@ -87,7 +88,7 @@
 //!     }
 //! });
 //!
-//! let mut test = JsonTest::new(&data);
+//! let test = JsonTest::new(&data);
 //!
 //! // Chain property assertions
 //! test.assert_path("$.user")
@@ -105,7 +106,7 @@
 //! # use json_test::JsonTest;
 //! # use serde_json::json;
 //! # let data = json!({"user": {"email": "test@example.com"}});
-//! # let mut test = JsonTest::new(&data);
+//! # let test = JsonTest::new(&data);
 //! test.assert_path("$.user.email")
 //!     .is_string()
 //!     .contains_string("@")
@@ -138,19 +139,40 @@
 //! minor breaking changes might occur before 1.0.
 
 mod assertions;
+mod color;
+mod compare;
 mod error;
 mod matchers;
 
+use assertions::base::{find_matches, locate, resolve_path};
 pub use assertions::base::JsonPathAssertion;
+pub use assertions::captures::CaptureAssertion;
+pub use assertions::each::EachAssertion;
+pub use assertions::negated::NegatedAssertion;
+pub use assertions::numeric_stats::NumericStats;
 pub use assertions::property_assertions::PropertyAssertions;
+pub use compare::{ComparisonAssertion, JsonCompare};
 pub use error::{ErrorContext, JsonPathError};
-pub use matchers::{JsonMatcher, RegexMatcher, TypeMatcher, ValueMatcher};
+pub use matchers::{
+    AndMatcher, JsonMatcher, LengthMatcher, NotMatcher, OrMatcher, RangeMatcher, RegexFlags, RegexMatcher,
+    TypeMatcher, ValueMatcher,
+};
 use serde_json::Value;
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
 
 /// Main entry point for JSON testing.
 ///
 /// `JsonTest` provides methods to create assertions on JSON values using JSONPath expressions.
-/// It maintains a reference to the JSON being tested and enables creation of chainable assertions.
+/// It either borrows or owns the JSON being tested and enables creation of chainable assertions.
+///
+/// Assertions only borrow the test immutably, so a single `JsonTest` can start
+/// any number of independent assertion chains.
 ///
 /// # Examples
 ///
@@ -167,20 +189,59 @@ use serde_json::Value;
 ///     }
 /// });
 ///
-/// let mut test = JsonTest::new(&data);
+/// let test = JsonTest::new(&data);
 ///
 /// // Test a single path with chained assertions
 /// test.assert_path("$.user")
 ///     .has_property("name")
 ///     .has_property("settings")
 ///     .has_property_value("name", json!("John"));
+///
+/// // Start another, independent chain from the same test
+/// test.assert_path("$.user.settings.theme")
+///     .equals(json!("dark"));
 /// ```
 #[derive(Debug)]
 pub struct JsonTest<'a> {
-    json: &'a Value,
+    json: Cow<'a, Value>,
+    /// Values already resolved for a path, keyed by the JSONPath expression.
+    cache: RefCell<HashMap<String, Vec<CachedMatch>>>,
+    /// Whether empty filter results are explained with a type mismatch hint.
+    strict_filters: bool,
+    /// Whether failure messages are highlighted with ANSI colors.
+    colors: bool,
+    /// Reads `NO_COLOR` and `CLICOLOR`; replaced in tests.
+    env: color::EnvLookup,
+    /// Failures collected by `assert_all`, or `None` when failures panic immediately.
+    failures: RefCell<Option<Vec<JsonPathError>>>,
+}
+
+/// A value resolved for a path, kept so that later lookups of the same path
+/// do not evaluate it again.
+///
+/// Nodes are kept by their normalized path rather than by reference, since
+/// the cache cannot borrow from the test that holds it.
+#[derive(Debug)]
+enum CachedMatch {
+    /// A node of the document, such as `$.['users'][0]`.
+    Node(String),
+    /// A value computed by the expression rather than found in the document,
+    /// such as the result of `length()`.
+    Computed(Value),
 }
 
 impl<'a> JsonTest<'a> {
+    fn with_json(json: Cow<'a, Value>) -> Self {
+        Self {
+            json,
+            cache: RefCell::new(HashMap::new()),
+            strict_filters: false,
+            colors: false,
+            env: |name| env::var_os(name),
+            failures: RefCell::new(None),
+        }
+    }
+
     /// Creates a new JSON test instance.
     ///
     /// Takes a reference to a JSON value that will be tested. The JSON value
@@ -195,7 +256,138 @@ impl<'a> JsonTest<'a> {
     /// let test = JsonTest::new(&data);
     /// ```
     pub fn new(json: &'a Value) -> Self {
-        Self { json }
+        Self::with_json(Cow::Borrowed(json))
+    }
+
+    /// Creates a new JSON test instance that owns the JSON value.
+    ///
+    /// Use this when the value is built or parsed inside the test and there is
+    /// no longer-lived binding to borrow from.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// let test = JsonTest::from_value(json!({"key": "value"}));
+    ///
+    /// test.assert_path("$.key").equals(json!("value"));
+    /// test.assert_path("$.missing").does_not_exist();
+    /// ```
+    pub fn from_value(json: Value) -> JsonTest<'static> {
+        JsonTest::with_json(Cow::Owned(json))
+    }
+
+    /// Creates a new JSON test instance by parsing a JSON string.
+    ///
+    /// The parsed value is owned by the test, as with `from_value`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// let test = JsonTest::from_str(r#"{"user": {"name": "John"}}"#);
+    ///
+    /// test.assert_path("$.user.name").equals(json!("John"));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics with the parser error if `json` is not valid JSON.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(json: &str) -> JsonTest<'static> {
+        match serde_json::from_str(json) {
+            Ok(value) => JsonTest::from_value(value),
+            Err(e) => panic!("Invalid JSON: {}", e),
+        }
+    }
+
+    /// Creates a new JSON test instance by reading and parsing a JSON file.
+    ///
+    /// Useful for fixture-driven tests. The parsed value is owned by the test.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// let test = JsonTest::from_file("tests/fixtures/user.json");
+    ///
+    /// test.assert_path("$.user.name").is_string();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the file cannot be read
+    /// - Panics if the file does not contain valid JSON
+    ///
+    /// Both messages include the path of the file.
+    pub fn from_file(path: impl AsRef<Path>) -> JsonTest<'static> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path)
+            .unwrap_or_else(|e| panic!("Failed to read JSON file {}: {}", path.display(), e));
+        match serde_json::from_str(&contents) {
+            Ok(value) => JsonTest::from_value(value),
+            Err(e) => panic!("Invalid JSON in {}: {}", path.display(), e),
+        }
+    }
+
+    /// Enables or disables hints for filter expressions that match nothing.
+    ///
+    /// With strict filters on, a failure on a path such as
+    /// `$.orders[?(@.total > 100)]` that matched nothing inspects the filtered
+    /// array and reports when the compared field has a different type than the
+    /// literal, for example a price sent as a string. Only simple comparisons
+    /// of a direct field against a string, number or boolean are inspected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,should_panic
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// let data = json!({"orders": [{"total": "150"}, {"total": "90"}]});
+    /// let test = JsonTest::new(&data).with_strict_filters(true);
+    ///
+    /// // Panics with:
+    /// // Path $.orders[?(@.total > 100)] does not exist
+    /// // Hint: field 'total' is a string in 2 of 2 elements; filter comparisons may not apply
+    /// test.assert_path("$.orders[?(@.total > 100)]").exists();
+    /// ```
+    pub fn with_strict_filters(mut self, enabled: bool) -> Self {
+        self.strict_filters = enabled;
+        self
+    }
+
+    /// Enables or disables colored failure messages.
+    ///
+    /// With colors on, a failing assertion panics with its first line in bold
+    /// red, the path in cyan, and `Expected`/`Actual` lines with their values
+    /// in green and red, which is easier to scan in long CI logs. Colors are
+    /// off by default so that panic messages stay stable, and stay off even
+    /// when enabled if the `NO_COLOR` environment variable is set to a
+    /// non-empty value or `CLICOLOR` is `0`. Methods that return a value, such
+    /// as `parse_json`, color their failures the same way. Failures collected
+    /// by `assert_all` are never colored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// let data = json!({"user": {"age": 30}});
+    /// let test = JsonTest::new(&data).with_colors(true);
+    ///
+    /// test.assert_path("$.user.age").equals(json!(30));
+    /// ```
+    pub fn with_colors(mut self, enabled: bool) -> Self {
+        self.colors = enabled;
+        self
+    }
+
+    /// Whether failures should be highlighted, taking the environment into account.
+    pub(crate) fn colors_enabled(&self) -> bool {
+        self.colors && color::env_allows_colors(self.env)
     }
 
     /// Creates a new assertion for the given JSONPath expression.
@@ -215,7 +407,7 @@ impl<'a> JsonTest<'a> {
     ///     ]
     /// });
     ///
-    /// let mut test = JsonTest::new(&data);
+    /// let test = JsonTest::new(&data);
     ///
     /// // Test array element with chained assertions
     /// test.assert_path("$.users[0]")
@@ -227,7 +419,728 @@ impl<'a> JsonTest<'a> {
     ///
     /// Panics if the JSONPath expression is invalid. This is appropriate for
     /// testing scenarios where invalid paths indicate test specification errors.
-    pub fn assert_path(&'a mut self, path: &str) -> JsonPathAssertion<'a> {
-        JsonPathAssertion::new_with_test(self, self.json, path)
+    /// Paths that start with neither `$` nor `@` are rejected with a suggested
+    /// fix, so `user.name` reports `Did you mean '$.user.name'?`. A path
+    /// starting with `@` is evaluated from the root, like `$`.
+    pub fn assert_path(&self, path: &str) -> JsonPathAssertion<'_> {
+        JsonPathAssertion::new_with_test(self, path)
     }
-}
\ No newline at end of file
+
+    /// Creates a new assertion for a literal child key of the object at `parent_path`.
+    ///
+    /// The key is looked up directly rather than spliced into a JSONPath
+    /// expression, so it may contain dots, spaces, `@`, quotes or any other
+    /// characters that would otherwise need careful bracket quoting. A missing
+    /// key yields an assertion with no value, so `exists` and `does_not_exist`
+    /// work as usual.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// let data = json!({"labels": {"app.kubernetes.io/name": "api", "it's": true}});
+    ///
+    /// let test = JsonTest::new(&data);
+    /// test.assert_key("$.labels", "app.kubernetes.io/name")
+    ///     .equals(json!("api"));
+    /// test.assert_key("$.labels", "it's")
+    ///     .equals(json!(true));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the JSONPath expression is invalid
+    /// - Panics if no value exists at `parent_path`
+    /// - Panics if the value at `parent_path` is not an object
+    pub fn assert_key(&self, parent_path: &str, key: &str) -> JsonPathAssertion<'_> {
+        let parent = self.resolve(parent_path).into_iter().next();
+        let child = match parent {
+            Some(Cow::Borrowed(Value::Object(obj))) => obj.get(key).map(Cow::Borrowed),
+            Some(Cow::Owned(Value::Object(mut obj))) => obj.remove(key).map(Cow::Owned),
+            Some(other) => panic!("Expected object at {}, got {:?}", parent_path, other),
+            None => panic!("No value found at {}", parent_path),
+        };
+        JsonPathAssertion::with_values(self, format!("{}['{}']", parent_path, key), child.into_iter().collect())
+    }
+
+    /// Creates a new assertion for a simplified, glob-style path.
+    ///
+    /// The glob is translated into a JSONPath expression relative to the root
+    /// and evaluated like any other path, so the assertion may hold several
+    /// values. Segments are separated by `.` and translated as follows:
+    ///
+    /// | Glob segment | JSONPath     | Meaning                         |
+    /// |--------------|--------------|---------------------------------|
+    /// | `name`       | `['name']`   | object property                 |
+    /// | `0`          | `[0]`        | array index                     |
+    /// | `*`          | `[*]`        | every element or property value |
+    /// | `**`         | `..`         | any depth below this point      |
+    ///
+    /// For example, `users.*.name` becomes `$['users'][*]['name']` and
+    /// `**.id` becomes `$..['id']`. An empty glob selects the root. Property
+    /// names containing quotes or backslashes are not supported; use
+    /// `assert_path` for anything beyond this subset.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// let data = json!({
+    ///     "users": [
+    ///         {"name": "John", "address": {"city": "Paris"}},
+    ///         {"name": "Jane", "address": {"city": "Rome"}}
+    ///     ]
+    /// });
+    ///
+    /// let test = JsonTest::new(&data);
+    /// test.assert_glob("users.*.name")
+    ///     .values_equal(&[json!("John"), json!("Jane")]);
+    /// test.assert_glob("users.1.**.city")
+    ///     .equals(json!("Rome"));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the glob has an empty segment or ends with `**`.
+    pub fn assert_glob(&self, glob: &str) -> JsonPathAssertion<'_> {
+        self.assert_path(&glob_to_jsonpath(glob))
+    }
+
+    /// Returns the JSON document under test.
+    pub(crate) fn json(&self) -> &Value {
+        &self.json
+    }
+
+    /// Resolves a path against the document, reusing earlier results for the same path.
+    ///
+    /// The document never changes during a test, so cached entries are never
+    /// invalidated. A cache hit borrows the matched nodes from the document
+    /// without evaluating the path again.
+    pub(crate) fn resolve(&self, path: &str) -> Vec<Cow<'_, Value>> {
+        let mut cache = self.cache.borrow_mut();
+        if !cache.contains_key(path) {
+            let matches = find_matches(self.json(), path)
+                .into_iter()
+                .map(|found| match found.normalized {
+                    Some(normalized) => CachedMatch::Node(normalized),
+                    None => CachedMatch::Computed(found.value.into_owned()),
+                })
+                .collect();
+            cache.insert(path.to_string(), matches);
+        }
+        cache[path]
+            .iter()
+            .map(|found| match found {
+                CachedMatch::Node(normalized) => Cow::Borrowed(
+                    locate(self.json(), normalized)
+                        .unwrap_or_else(|| panic!("Matched node {} is missing from the document", normalized)),
+                ),
+                CachedMatch::Computed(value) => Cow::Owned(value.clone()),
+            })
+            .collect()
+    }
+
+    /// Records a failure if `assert_all` is collecting them.
+    ///
+    /// Returns the error back when failures should panic immediately.
+    pub(crate) fn collect_failure(&self, error: JsonPathError) -> Option<JsonPathError> {
+        match self.failures.borrow_mut().as_mut() {
+            Some(failures) => {
+                failures.push(error);
+                None
+            }
+            None => Some(error),
+        }
+    }
+
+    /// Runs a block of assertions in soft mode, reporting every failure at the end.
+    ///
+    /// Inside the block, assertions are written exactly as usual. A failing
+    /// assertion is recorded instead of panicking and returns normally, so the
+    /// chain and the rest of the block keep running. When the block finishes,
+    /// all recorded failures are reported in one panic, grouped by path as in
+    /// `JsonPathError::grouped_report`.
+    ///
+    /// Failures of `JsonPathAssertion` and `PropertyAssertions` methods that
+    /// return the assertion are collected. Anything else that panics inside the
+    /// block, such as an invalid JSONPath expression or a method that returns a
+    /// value, ends the block early; the failures collected so far are still
+    /// reported, followed by that panic message.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,should_panic
+    /// # use json_test::{JsonTest, PropertyAssertions};
+    /// # use serde_json::json;
+    /// let data = json!({"user": {"name": "John", "age": "30", "role": "guest"}});
+    /// let test = JsonTest::new(&data);
+    ///
+    /// // Panics once, listing all three failures
+    /// test.assert_all(|test| {
+    ///     test.assert_path("$.user.name").equals(json!("Jane"));
+    ///     test.assert_path("$.user.age").is_number();
+    ///     test.assert_path("$.user")
+    ///         .has_property("email")
+    ///         .has_property_value("role", json!("guest"));
+    /// });
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics after the block has run if any assertion inside it failed.
+    pub fn assert_all<F>(&self, block: F)
+    where
+        F: FnOnce(&Self),
+    {
+        if self.failures.borrow().is_some() {
+            // Already collecting for an enclosing block, which reports at its end
+            block(self);
+            return;
+        }
+
+        *self.failures.borrow_mut() = Some(Vec::new());
+        let outcome = panic::catch_unwind(AssertUnwindSafe(|| block(self)));
+        let failures = self.failures.borrow_mut().take().unwrap_or_default();
+
+        match outcome {
+            Ok(()) if failures.is_empty() => {}
+            Ok(()) => panic!("{}", JsonPathError::grouped_report(&failures)),
+            Err(payload) if failures.is_empty() => panic::resume_unwind(payload),
+            Err(payload) => {
+                let message = payload
+                    .downcast_ref::<String>()
+                    .map(String::as_str)
+                    .or_else(|| payload.downcast_ref::<&str>().copied())
+                    .unwrap_or("(non-string panic payload)");
+                panic!(
+                    "{}\n\nAborted by: {}",
+                    JsonPathError::grouped_report(&failures),
+                    message
+                );
+            }
+        }
+    }
+
+    /// Asserts that the values at the given paths are pairwise distinct.
+    ///
+    /// Each path must resolve to exactly one value. This expresses uniqueness
+    /// invariants between unrelated fields, such as several IDs that must differ.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// let data = json!({
+    ///     "order_id": "ord_1",
+    ///     "customer": {"id": "cus_1"},
+    ///     "payment": {"id": "pay_1"}
+    /// });
+    ///
+    /// let test = JsonTest::new(&data);
+    /// test.assert_paths_distinct(["$.order_id", "$.customer.id", "$.payment.id"]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if any JSONPath expression is invalid
+    /// - Panics if any path does not exist or matches more than one value
+    /// - Panics if two paths resolve to equal values
+    pub fn assert_paths_distinct<'p, I>(&self, paths: I) -> &Self
+    where
+        I: IntoIterator<Item = &'p str>,
+    {
+        let mut resolved: Vec<(&str, Cow<Value>)> = Vec::new();
+        let mut missing = Vec::new();
+        for path in paths {
+            let mut values = self.resolve(path);
+            match values.len() {
+                0 => missing.push(path),
+                1 => resolved.push((path, values.remove(0))),
+                n => panic!("Path {} matched {} values, expected exactly one", path, n),
+            }
+        }
+
+        if !missing.is_empty() {
+            panic!("Paths do not exist: {}", missing.join(", "));
+        }
+
+        for (i, (path, value)) in resolved.iter().enumerate() {
+            if let Some((other_path, _)) = resolved[i + 1..].iter().find(|(_, other)| other == value) {
+                panic!("Paths {} and {} have equal values: {}", path, other_path, value);
+            }
+        }
+        self
+    }
+
+    /// Creates a comparison between two documents, such as two versions of an API response.
+    ///
+    /// Paths asserted on the returned `JsonCompare` are resolved in both documents.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// let v1 = json!({"items": [{"id": 1}], "next": null});
+    /// let v2 = json!({"items": [{"id": 1}], "next_cursor": "abc"});
+    ///
+    /// let compare = JsonTest::compare(&v1, &v2);
+    /// compare.assert_path("$.items").values_equal();
+    /// compare.assert_path("$.next").only_in_first();
+    /// ```
+    pub fn compare(first: &'a Value, second: &'a Value) -> JsonCompare<'a> {
+        JsonCompare::new(first, second)
+    }
+
+    /// Asserts that the value at a path differs between two documents.
+    ///
+    /// The path is resolved in both `before` and `after`. A path that exists
+    /// in only one of the documents counts as changed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// let before = json!({"user": {"name": "John", "updated_at": 1}});
+    /// let after = json!({"user": {"name": "John", "updated_at": 2}});
+    ///
+    /// JsonTest::assert_changed(&before, &after, "$.user.updated_at");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the JSONPath expression is invalid
+    /// - Panics if the value at the path is the same in both documents
+    pub fn assert_changed(before: &Value, after: &Value, path: &str) {
+        let old = resolve_path(before, path);
+        let new = resolve_path(after, path);
+        if old == new {
+            panic!(
+                "Value at {} did not change\nValue: {}",
+                path,
+                describe_values(&old)
+            );
+        }
+    }
+
+    /// Asserts that the value at a path is the same in two documents.
+    ///
+    /// The path is resolved in both `before` and `after`. A path missing from
+    /// both documents counts as unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use json_test::JsonTest;
+    /// # use serde_json::json;
+    /// let before = json!({"user": {"name": "John", "updated_at": 1}});
+    /// let after = json!({"user": {"name": "John", "updated_at": 2}});
+    ///
+    /// JsonTest::assert_unchanged(&before, &after, "$.user.name");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the JSONPath expression is invalid
+    /// - Panics if the value at the path differs between the documents
+    pub fn assert_unchanged(before: &Value, after: &Value, path: &str) {
+        let old = resolve_path(before, path);
+        let new = resolve_path(after, path);
+        if old != new {
+            panic!(
+                "Value at {} changed\nBefore: {}\nAfter: {}",
+                path,
+                describe_values(&old),
+                describe_values(&new)
+            );
+        }
+    }
+}
+
+/// Formats the values matched by a path for error messages.
+pub(crate) fn describe_values(values: &[Cow<Value>]) -> String {
+    match values {
+        [] => "(missing)".to_string(),
+        [value] => value.to_string(),
+        values => Value::Array(values.iter().map(|v| v.clone().into_owned()).collect()).to_string(),
+    }
+}
+
+/// Translates a glob-style path such as `users.*.name` into a JSONPath expression.
+fn glob_to_jsonpath(glob: &str) -> String {
+    let mut path = String::from("$");
+    if glob.is_empty() {
+        return path;
+    }
+
+    let mut descend = false;
+    for segment in glob.split('.') {
+        if segment == "**" {
+            descend = true;
+            continue;
+        }
+        if descend {
+            path.push_str("..");
+            descend = false;
+        }
+        match segment {
+            "" => panic!("Invalid glob '{}': empty segment", glob),
+            "*" => path.push_str("[*]"),
+            index if index.bytes().all(|b| b.is_ascii_digit()) => {
+                path.push_str(&format!("[{}]", index));
+            }
+            key => path.push_str(&format!("['{}']", key)),
+        }
+    }
+
+    if descend {
+        panic!("Invalid glob '{}': '**' must be followed by a segment", glob);
+    }
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_independent_assertions() {
+        let data = json!({"user": {"name": "John", "age": 30}});
+        let test = JsonTest::new(&data);
+
+        let mut name = test.assert_path("$.user.name");
+        test.assert_path("$.user.age").equals(json!(30));
+        name.equals(json!("John"));
+        test.assert_path("$.user").exists();
+    }
+
+    #[test]
+    fn test_stored_assertion_reused() {
+        let data = json!({"a": "x"});
+        let test = JsonTest::new(&data);
+
+        let mut a = test.assert_path("$.a");
+        a.is_string();
+        a.equals(json!("x"));
+        a.not().equals(json!("y"));
+    }
+
+    #[test]
+    fn test_repeated_paths_give_identical_results() {
+        let data = json!({"users": [{"age": 30}, {"age": 25}], "tags": ["a", "b"]});
+        let test = JsonTest::new(&data);
+
+        for _ in 0..2 {
+            test.assert_path("$.users[*].age").values_equal(&[json!(30), json!(25)]);
+            test.assert_path("$.tags.length()").equals(json!(2));
+            test.assert_path("$.missing").does_not_exist();
+        }
+
+        let owned = JsonTest::from_value(data.clone());
+        for _ in 0..2 {
+            owned.assert_path("$.users[*].age").values_equal(&[json!(30), json!(25)]);
+            owned.assert_path("$.tags.length()").equals(json!(2));
+        }
+    }
+
+    #[test]
+    fn test_cache_hits_borrow_the_document() {
+        let data = json!({"users": [{"age": 30}, {"age": 25}], "tags": ["a", "b"]});
+        let test = JsonTest::new(&data);
+        test.assert_path("$.users[*].age");
+
+        let hit = test.assert_path("$.users[*].age").current_values;
+        assert!(matches!(hit[0], Cow::Borrowed(node) if std::ptr::eq(node, &data["users"][0]["age"])));
+
+        test.assert_path("$.tags.length()");
+        let computed = test.assert_path("$.tags.length()").current_values;
+        assert_eq!(computed, vec![Cow::Borrowed(&json!(2))]);
+    }
+
+    #[test]
+    fn test_cache_survives_moving_an_owned_document() {
+        let test = JsonTest::from_value(json!({"user": {"name": "John"}, "tags": ["a"]}));
+        assert_eq!(test.assert_path("$").current_values.len(), 1);
+        test.assert_path("$.user.name").equals(json!("John"));
+
+        let moved = Box::new(test);
+        moved.assert_path("$").has_property("tags");
+        moved.assert_path("$.user.name").equals(json!("John"));
+    }
+
+    #[test]
+    fn test_assert_key() {
+        let data = json!({"labels": {"app.kubernetes.io/name": "api", "user name": "John", "@type": "x"}});
+        let test = JsonTest::new(&data);
+
+        test.assert_key("$.labels", "app.kubernetes.io/name").equals(json!("api"));
+        test.assert_key("$.labels", "user name").equals(json!("John"));
+        test.assert_key("$.labels", "@type").equals(json!("x"));
+        test.assert_key("$.labels", "missing").does_not_exist();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected object at $.labels, got Array [String(\"a\")]")]
+    fn test_assert_key_parent_not_object() {
+        let data = json!({"labels": ["a"]});
+        let test = JsonTest::new(&data);
+        test.assert_key("$.labels", "a");
+    }
+
+    #[test]
+    fn test_from_value() {
+        let test = JsonTest::from_value(json!({"items": [1, 2, 3]}));
+        test.assert_path("$.items").has_length(3);
+        test.assert_path("$.items[0]").equals(json!(1));
+    }
+
+    #[test]
+    fn test_glob_to_jsonpath() {
+        assert_eq!(glob_to_jsonpath(""), "$");
+        assert_eq!(glob_to_jsonpath("users.*.name"), "$['users'][*]['name']");
+        assert_eq!(glob_to_jsonpath("users.0"), "$['users'][0]");
+        assert_eq!(glob_to_jsonpath("**.id"), "$..['id']");
+        assert_eq!(glob_to_jsonpath("a.**.**.b"), "$['a']..['b']");
+        assert_eq!(glob_to_jsonpath("user name.@type"), "$['user name']['@type']");
+    }
+
+    #[test]
+    fn test_assert_glob() {
+        let data = json!({"users": [{"name": "a", "meta": {"id": 1}}, {"name": "b", "meta": {"id": 2}}]});
+        let test = JsonTest::new(&data);
+        test.assert_glob("users.*.name").values_equal(&[json!("a"), json!("b")]);
+        test.assert_glob("users.**.id").values_equal(&[json!(1), json!(2)]);
+        test.assert_glob("users.1.name").equals(json!("b"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid glob 'users..name': empty segment")]
+    fn test_assert_glob_empty_segment() {
+        let data = json!({});
+        JsonTest::new(&data).assert_glob("users..name");
+    }
+
+    #[test]
+    fn test_assert_changed_and_unchanged() {
+        let before = json!({"count": 1, "name": "a"});
+        let after = json!({"count": 2, "name": "a", "extra": true});
+        JsonTest::assert_changed(&before, &after, "$.count");
+        JsonTest::assert_changed(&before, &after, "$.extra");
+        JsonTest::assert_unchanged(&before, &after, "$.name");
+        JsonTest::assert_unchanged(&before, &after, "$.missing");
+    }
+
+    #[test]
+    #[should_panic(expected = "Value at $.name did not change\nValue: \"a\"")]
+    fn test_assert_changed_fails() {
+        let before = json!({"name": "a"});
+        JsonTest::assert_changed(&before, &before.clone(), "$.name");
+    }
+
+    #[test]
+    #[should_panic(expected = "Value at $.count changed\nBefore: 1\nAfter: (missing)")]
+    fn test_assert_unchanged_fails() {
+        let before = json!({"count": 1});
+        let after = json!({});
+        JsonTest::assert_unchanged(&before, &after, "$.count");
+    }
+
+    #[test]
+    fn test_assert_paths_distinct() {
+        let data = json!({"a": 1, "b": {"id": 2}, "c": "1"});
+        let test = JsonTest::new(&data);
+        test.assert_paths_distinct(["$.a", "$.b.id", "$.c"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Paths $.a and $.c have equal values: 1")]
+    fn test_assert_paths_distinct_duplicate() {
+        let data = json!({"a": 1, "b": 2, "c": 1});
+        let test = JsonTest::new(&data);
+        test.assert_paths_distinct(["$.a", "$.b", "$.c"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Paths do not exist: $.x, $.y")]
+    fn test_assert_paths_distinct_missing() {
+        let data = json!({"a": 1});
+        let test = JsonTest::new(&data);
+        test.assert_paths_distinct(["$.x", "$.a", "$.y"]);
+    }
+
+    fn panic_message(result: std::thread::Result<()>) -> String {
+        let payload = result.unwrap_err();
+        match payload.downcast::<String>() {
+            Ok(message) => *message,
+            Err(payload) => payload.downcast::<&str>().map(|s| s.to_string()).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_assert_all_reports_every_failure() {
+        let json = json!({"user": {"name": "John", "age": "30", "role": "guest"}});
+        let result = std::panic::catch_unwind(|| {
+            let test = JsonTest::new(&json);
+            test.assert_all(|test| {
+                test.assert_path("$.user.name").equals(json!("Jane"));
+                test.assert_path("$.user.age").is_number().is_greater_than(18);
+                test.assert_path("$.user")
+                    .has_property("email")
+                    .has_property_value("role", json!("admin"));
+            });
+        });
+
+        let message = panic_message(result);
+        // The failed is_number and is_greater_than checks report the same message once
+        assert!(message.starts_with("3 failing paths, 4 failures"), "{}", message);
+        assert!(message.contains("$.user.name\n    Value mismatch at $.user.name\n    Expected: \"Jane\"\n    Actual: \"John\"\n\n"));
+        assert!(message.contains("$.user.age\n    Expected number at $.user.age, got String(\"30\")\n"));
+        assert!(message.contains("Property 'email' not found at $.user"));
+        assert!(message.contains("Property 'role' value mismatch at $.user"));
+    }
+
+    #[test]
+    fn test_assert_all_passes() {
+        let json = json!({"user": {"name": "John"}});
+        let test = JsonTest::new(&json);
+        test.assert_all(|test| {
+            test.assert_path("$.user.name").is_string().equals(json!("John"));
+        });
+        assert!(test.failures.borrow().is_none());
+    }
+
+    #[test]
+    fn test_assert_all_aborted_block() {
+        let json = json!({"a": 1, "b": [1, 2]});
+        let result = std::panic::catch_unwind(|| {
+            let test = JsonTest::new(&json);
+            test.assert_all(|test| {
+                test.assert_path("$.a").equals(json!(2));
+                test.assert_path("$.a").array_length();
+                test.assert_path("$.b").has_length(5);
+            });
+        });
+
+        let message = panic_message(result);
+        assert!(message.starts_with("1 failing path, 1 failure"), "{}", message);
+        assert!(message.ends_with("Aborted by: Expected array at $.a, got Number(1)"), "{}", message);
+    }
+
+    #[test]
+    fn test_assert_all_aborted_by_unknown_schema_type() {
+        let json = json!({"a": 1});
+        let result = std::panic::catch_unwind(|| {
+            let test = JsonTest::new(&json);
+            test.assert_all(|test| {
+                test.assert_path("$.a").equals(json!(2));
+                test.assert_path("$.a").is_schema_type("int");
+            });
+        });
+
+        let message = panic_message(result);
+        assert!(message.ends_with("Aborted by: Unknown JSON Schema type 'int'"), "{}", message);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value mismatch at $.a")]
+    fn test_failures_panic_outside_assert_all() {
+        let json = json!({"a": 1});
+        let test = JsonTest::new(&json);
+        test.assert_all(|test| {
+            test.assert_path("$.a").equals(json!(1));
+        });
+        test.assert_path("$.a").equals(json!(2));
+    }
+
+    #[test]
+    fn test_from_str() {
+        let test = JsonTest::from_str(r#"{"user": {"name": "John", "tags": ["a"]}}"#);
+        test.assert_path("$.user.name").equals(json!("John"));
+        test.assert_path("$.user.tags").has_length(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid JSON: EOF while parsing an object")]
+    fn test_from_str_malformed() {
+        JsonTest::from_str(r#"{"user": {"name": "John"}"#);
+    }
+
+    #[test]
+    fn test_from_file() {
+        let path = std::env::temp_dir().join(format!("json-test-from-file-{}.json", std::process::id()));
+        fs::write(&path, r#"{"items": [1, 2, 3]}"#).unwrap();
+        let test = JsonTest::from_file(&path);
+        fs::remove_file(&path).unwrap();
+
+        test.assert_path("$.items").has_length(3);
+    }
+
+    #[test]
+    fn test_from_file_errors() {
+        let path = std::env::temp_dir().join(format!("json-test-malformed-{}.json", std::process::id()));
+        fs::write(&path, "{not json}").unwrap();
+        let message = panic_message(std::panic::catch_unwind(|| {
+            JsonTest::from_file(&path);
+        }));
+        fs::remove_file(&path).unwrap();
+        assert!(message.starts_with(&format!("Invalid JSON in {}: key must be a string", path.display())));
+
+        let message = panic_message(std::panic::catch_unwind(|| {
+            JsonTest::from_file(&path);
+        }));
+        assert!(message.starts_with(&format!("Failed to read JSON file {}", path.display())));
+    }
+
+    #[test]
+    fn test_failures_uncolored_by_default() {
+        let json = json!({"user": {"age": 30}});
+        let test = JsonTest::new(&json);
+        let message = panic_message(std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            test.assert_path("$.user.age").equals(json!(25));
+        })));
+        assert_eq!(message, "Value mismatch at $.user.age\nExpected: 25\nActual: 30");
+    }
+
+    #[test]
+    fn test_failures_colored_with_colors() {
+        let json = json!({"user": {"age": 30}, "meta": "{"});
+        let mut test = JsonTest::new(&json).with_colors(true);
+        test.env = |_| None;
+
+        let message = panic_message(std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            test.assert_path("$.user.age").equals(json!(25));
+        })));
+        assert_eq!(
+            message,
+            color::highlight("Value mismatch at $.user.age\nExpected: 25\nActual: 30", "$.user.age")
+        );
+        assert!(message.contains("\x1b[36m$.user.age\x1b[0m"));
+
+        let message = panic_message(std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            test.assert_path("$.meta").parse_json();
+        })));
+        assert!(message.contains("\x1b[36m$.meta\x1b[0m"));
+
+        let message = panic_message(std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            test.assert_path("$.meta").array_length();
+        })));
+        assert!(message.contains("\x1b[36m$.meta\x1b[0m"));
+    }
+
+    #[test]
+    fn test_no_color_suppresses_colors() {
+        let json = json!({"user": {"age": 30}});
+        let mut test = JsonTest::new(&json).with_colors(true);
+        test.env = |name| (name == "NO_COLOR").then(|| "1".into());
+
+        let message = panic_message(std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            test.assert_path("$.user.age").equals(json!(25));
+        })));
+        assert_eq!(message, "Value mismatch at $.user.age\nExpected: 25\nActual: 30");
+    }
+}
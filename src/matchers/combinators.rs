@@ -0,0 +1,210 @@
+use super::JsonMatcher;
+use serde_json::Value;
+
+/// Matches when every inner matcher matches.
+///
+/// Inner matchers are tried in order and evaluation stops at the first one
+/// that does not match. An `AndMatcher` with no inner matchers matches any value.
+///
+/// # Examples
+///
+/// ```rust
+/// use json_test::{AndMatcher, JsonMatcher, RegexMatcher, TypeMatcher};
+/// use serde_json::json;
+///
+/// let matcher = AndMatcher::both(TypeMatcher::string(), RegexMatcher::new(r"^\d+$").unwrap());
+/// assert!(matcher.matches(&json!("42")));
+/// assert!(!matcher.matches(&json!(42)));
+/// ```
+#[derive(Debug)]
+pub struct AndMatcher {
+    matchers: Vec<Box<dyn JsonMatcher>>,
+}
+
+impl AndMatcher {
+    pub fn new(matchers: Vec<Box<dyn JsonMatcher>>) -> Self {
+        Self { matchers }
+    }
+
+    pub fn both(first: impl JsonMatcher + 'static, second: impl JsonMatcher + 'static) -> Self {
+        Self::new(vec![Box::new(first), Box::new(second)])
+    }
+}
+
+impl JsonMatcher for AndMatcher {
+    fn matches(&self, value: &Value) -> bool {
+        self.matchers.iter().all(|matcher| matcher.matches(value))
+    }
+
+    fn description(&self) -> String {
+        join_descriptions(&self.matchers, " and ", "matches anything")
+    }
+}
+
+/// Matches when at least one inner matcher matches.
+///
+/// Inner matchers are tried in order and evaluation stops at the first one
+/// that matches. An `OrMatcher` with no inner matchers matches nothing.
+///
+/// # Examples
+///
+/// ```rust
+/// use json_test::{JsonMatcher, OrMatcher, TypeMatcher};
+/// use serde_json::json;
+///
+/// let matcher = OrMatcher::either(TypeMatcher::string(), TypeMatcher::null());
+/// assert!(matcher.matches(&json!(null)));
+/// assert!(!matcher.matches(&json!(0)));
+/// ```
+#[derive(Debug)]
+pub struct OrMatcher {
+    matchers: Vec<Box<dyn JsonMatcher>>,
+}
+
+impl OrMatcher {
+    pub fn new(matchers: Vec<Box<dyn JsonMatcher>>) -> Self {
+        Self { matchers }
+    }
+
+    pub fn either(first: impl JsonMatcher + 'static, second: impl JsonMatcher + 'static) -> Self {
+        Self::new(vec![Box::new(first), Box::new(second)])
+    }
+}
+
+impl JsonMatcher for OrMatcher {
+    fn matches(&self, value: &Value) -> bool {
+        self.matchers.iter().any(|matcher| matcher.matches(value))
+    }
+
+    fn description(&self) -> String {
+        join_descriptions(&self.matchers, " or ", "matches nothing")
+    }
+}
+
+/// Matches when the inner matcher does not.
+///
+/// # Examples
+///
+/// ```rust
+/// use json_test::{JsonMatcher, NotMatcher, ValueMatcher};
+/// use serde_json::json;
+///
+/// let matcher = NotMatcher::new(ValueMatcher::eq(json!("deleted")));
+/// assert!(matcher.matches(&json!("active")));
+/// assert_eq!(matcher.description(), "not (equals \"deleted\")");
+/// ```
+#[derive(Debug)]
+pub struct NotMatcher {
+    matcher: Box<dyn JsonMatcher>,
+}
+
+impl NotMatcher {
+    pub fn new(matcher: impl JsonMatcher + 'static) -> Self {
+        Self { matcher: Box::new(matcher) }
+    }
+}
+
+impl JsonMatcher for NotMatcher {
+    fn matches(&self, value: &Value) -> bool {
+        !self.matcher.matches(value)
+    }
+
+    fn description(&self) -> String {
+        format!("not ({})", self.matcher.description())
+    }
+}
+
+/// Joins inner descriptions, parenthesizing each when there are several.
+fn join_descriptions(matchers: &[Box<dyn JsonMatcher>], separator: &str, empty: &str) -> String {
+    match matchers {
+        [] => empty.to_string(),
+        [only] => only.description(),
+        _ => matchers
+            .iter()
+            .map(|matcher| format!("({})", matcher.description()))
+            .collect::<Vec<_>>()
+            .join(separator),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matchers::{RegexMatcher, TypeMatcher, ValueMatcher};
+    use serde_json::json;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    /// Matcher that records how often it was called
+    #[derive(Debug)]
+    struct CountingMatcher {
+        result: bool,
+        calls: Rc<Cell<usize>>,
+    }
+
+    impl JsonMatcher for CountingMatcher {
+        fn matches(&self, _: &Value) -> bool {
+            self.calls.set(self.calls.get() + 1);
+            self.result
+        }
+
+        fn description(&self) -> String {
+            format!("always returns {}", self.result)
+        }
+    }
+
+    fn counting(result: bool, calls: &Rc<Cell<usize>>) -> CountingMatcher {
+        CountingMatcher { result, calls: Rc::clone(calls) }
+    }
+
+    #[test]
+    fn test_and_matcher() {
+        let matcher = AndMatcher::both(TypeMatcher::string(), RegexMatcher::new(r"^\d+$").unwrap());
+        assert!(matcher.matches(&json!("123")));
+        assert!(!matcher.matches(&json!("abc")));
+        assert!(!matcher.matches(&json!(123)));
+        assert!(AndMatcher::new(vec![]).matches(&json!(null)));
+    }
+
+    #[test]
+    fn test_or_matcher() {
+        let matcher = OrMatcher::either(TypeMatcher::number(), ValueMatcher::eq(json!("n/a")));
+        assert!(matcher.matches(&json!(1.5)));
+        assert!(matcher.matches(&json!("n/a")));
+        assert!(!matcher.matches(&json!("1.5")));
+        assert!(!OrMatcher::new(vec![]).matches(&json!(null)));
+    }
+
+    #[test]
+    fn test_not_matcher() {
+        let matcher = NotMatcher::new(TypeMatcher::null());
+        assert!(matcher.matches(&json!(0)));
+        assert!(!matcher.matches(&json!(null)));
+    }
+
+    #[test]
+    fn test_short_circuit() {
+        let calls = Rc::new(Cell::new(0));
+        let and = AndMatcher::both(counting(false, &calls), counting(true, &calls));
+        assert!(!and.matches(&json!(1)));
+        assert_eq!(calls.get(), 1);
+
+        calls.set(0);
+        let or = OrMatcher::either(counting(true, &calls), counting(false, &calls));
+        assert!(or.matches(&json!(1)));
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn test_composed_descriptions() {
+        let matcher = OrMatcher::new(vec![
+            Box::new(AndMatcher::both(TypeMatcher::string(), RegexMatcher::new(r"\d+").unwrap())),
+            Box::new(NotMatcher::new(ValueMatcher::eq(json!(0)))),
+        ]);
+        assert_eq!(
+            matcher.description(),
+            r"((is of type string) and (matches regex pattern \d+)) or (not (equals 0))"
+        );
+        assert_eq!(AndMatcher::new(vec![Box::new(TypeMatcher::null())]).description(), "is of type null");
+    }
+}
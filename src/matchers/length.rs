@@ -0,0 +1,105 @@
+use super::JsonMatcher;
+use serde_json::Value;
+
+/// Matches strings, arrays and objects by length.
+///
+/// Strings are measured in characters (Unicode scalar values), arrays in
+/// elements and objects in properties. Other values never match.
+///
+/// # Examples
+///
+/// ```rust
+/// use json_test::{JsonMatcher, LengthMatcher};
+/// use serde_json::json;
+///
+/// let matcher = LengthMatcher::between(1, 3);
+/// assert!(matcher.matches(&json!(["a", "b"])));
+/// assert!(matcher.matches(&json!("héé")));
+/// assert!(!matcher.matches(&json!({})));
+/// assert_eq!(matcher.description(), "has length between 1 and 3");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct LengthMatcher {
+    min: usize,
+    max: usize,
+}
+
+impl LengthMatcher {
+    pub fn exactly(len: usize) -> Self {
+        Self { min: len, max: len }
+    }
+
+    pub fn at_least(min: usize) -> Self {
+        Self { min, max: usize::MAX }
+    }
+
+    pub fn at_most(max: usize) -> Self {
+        Self { min: 0, max }
+    }
+
+    pub fn between(min: usize, max: usize) -> Self {
+        Self { min, max }
+    }
+}
+
+impl JsonMatcher for LengthMatcher {
+    fn matches(&self, value: &Value) -> bool {
+        let len = match value {
+            Value::String(s) => s.chars().count(),
+            Value::Array(arr) => arr.len(),
+            Value::Object(obj) => obj.len(),
+            _ => return false,
+        };
+        (self.min..=self.max).contains(&len)
+    }
+
+    fn description(&self) -> String {
+        match (self.min, self.max) {
+            (min, max) if min == max => format!("has length exactly {}", min),
+            (min, usize::MAX) => format!("has length at least {}", min),
+            (0, max) => format!("has length at most {}", max),
+            (min, max) => format!("has length between {} and {}", min, max),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_string_length() {
+        assert!(LengthMatcher::exactly(5).matches(&json!("héllo")));
+        assert!(!LengthMatcher::exactly(6).matches(&json!("héllo")));
+        assert!(LengthMatcher::exactly(0).matches(&json!("")));
+    }
+
+    #[test]
+    fn test_array_length() {
+        assert!(LengthMatcher::at_least(2).matches(&json!([1, 2, 3])));
+        assert!(!LengthMatcher::at_least(4).matches(&json!([1, 2, 3])));
+        assert!(LengthMatcher::at_most(3).matches(&json!([1, 2, 3])));
+    }
+
+    #[test]
+    fn test_object_length() {
+        assert!(LengthMatcher::between(1, 2).matches(&json!({"a": 1, "b": 2})));
+        assert!(!LengthMatcher::between(1, 2).matches(&json!({})));
+    }
+
+    #[test]
+    fn test_non_collections() {
+        assert!(!LengthMatcher::at_least(0).matches(&json!(12345)));
+        assert!(!LengthMatcher::at_least(0).matches(&json!(null)));
+        assert!(!LengthMatcher::at_least(0).matches(&json!(true)));
+    }
+
+    #[test]
+    fn test_description() {
+        assert_eq!(LengthMatcher::exactly(3).description(), "has length exactly 3");
+        assert_eq!(LengthMatcher::at_least(1).description(), "has length at least 1");
+        assert_eq!(LengthMatcher::at_most(10).description(), "has length at most 10");
+        assert_eq!(LengthMatcher::between(2, 4).description(), "has length between 2 and 4");
+    }
+}
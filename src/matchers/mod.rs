@@ -1,8 +1,14 @@
+mod combinators;
+mod length;
+mod range;
 mod regex;
 mod type_matcher;
 mod value;
 
-pub use regex::RegexMatcher;
+pub use combinators::{AndMatcher, NotMatcher, OrMatcher};
+pub use length::LengthMatcher;
+pub use range::RangeMatcher;
+pub use regex::{RegexFlags, RegexMatcher};
 pub use type_matcher::TypeMatcher;
 pub use value::ValueMatcher;
 
@@ -0,0 +1,154 @@
+use super::JsonMatcher;
+use serde_json::Value;
+
+/// Matches numbers within a range whose bounds can be inclusive or exclusive.
+///
+/// A new matcher is unbounded on both sides; set each bound with one of the
+/// builder methods. Non-number values never match.
+///
+/// # Examples
+///
+/// ```rust
+/// use json_test::{JsonMatcher, RangeMatcher};
+/// use serde_json::json;
+///
+/// let percentage = RangeMatcher::new().min_inclusive(0.0).max_exclusive(100.0);
+/// assert!(percentage.matches(&json!(0)));
+/// assert!(percentage.matches(&json!(99.5)));
+/// assert!(!percentage.matches(&json!(100)));
+/// assert_eq!(percentage.description(), "is in range [0, 100)");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct RangeMatcher {
+    min: Bound,
+    max: Bound,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Bound {
+    Unbounded,
+    Inclusive(f64),
+    Exclusive(f64),
+}
+
+impl RangeMatcher {
+    /// Creates a matcher with no bounds, which matches every number.
+    pub fn new() -> Self {
+        Self { min: Bound::Unbounded, max: Bound::Unbounded }
+    }
+
+    /// Creates a matcher for numbers between `min` and `max`, both inclusive.
+    pub fn between(min: f64, max: f64) -> Self {
+        Self::new().min_inclusive(min).max_inclusive(max)
+    }
+
+    /// Sets the lower bound to `min`, which itself matches.
+    pub fn min_inclusive(mut self, min: f64) -> Self {
+        self.min = Bound::Inclusive(min);
+        self
+    }
+
+    /// Sets the lower bound to `min`, which itself does not match.
+    pub fn min_exclusive(mut self, min: f64) -> Self {
+        self.min = Bound::Exclusive(min);
+        self
+    }
+
+    /// Sets the upper bound to `max`, which itself matches.
+    pub fn max_inclusive(mut self, max: f64) -> Self {
+        self.max = Bound::Inclusive(max);
+        self
+    }
+
+    /// Sets the upper bound to `max`, which itself does not match.
+    pub fn max_exclusive(mut self, max: f64) -> Self {
+        self.max = Bound::Exclusive(max);
+        self
+    }
+}
+
+impl Default for RangeMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl JsonMatcher for RangeMatcher {
+    fn matches(&self, value: &Value) -> bool {
+        let Some(n) = value.as_f64() else {
+            return false;
+        };
+        let above_min = match self.min {
+            Bound::Unbounded => true,
+            Bound::Inclusive(min) => n >= min,
+            Bound::Exclusive(min) => n > min,
+        };
+        let below_max = match self.max {
+            Bound::Unbounded => true,
+            Bound::Inclusive(max) => n <= max,
+            Bound::Exclusive(max) => n < max,
+        };
+        above_min && below_max
+    }
+
+    fn description(&self) -> String {
+        let min = match self.min {
+            Bound::Unbounded => "(-inf".to_string(),
+            Bound::Inclusive(min) => format!("[{}", min),
+            Bound::Exclusive(min) => format!("({}", min),
+        };
+        let max = match self.max {
+            Bound::Unbounded => "inf)".to_string(),
+            Bound::Inclusive(max) => format!("{}]", max),
+            Bound::Exclusive(max) => format!("{})", max),
+        };
+        format!("is in range {}, {}", min, max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_inclusive_bounds() {
+        let matcher = RangeMatcher::between(1.0, 5.0);
+        assert!(matcher.matches(&json!(1)));
+        assert!(matcher.matches(&json!(5.0)));
+        assert!(matcher.matches(&json!(3.5)));
+        assert!(!matcher.matches(&json!(0.99)));
+        assert!(!matcher.matches(&json!(6)));
+    }
+
+    #[test]
+    fn test_exclusive_bounds() {
+        let matcher = RangeMatcher::new().min_exclusive(0.0).max_exclusive(1.0);
+        assert!(!matcher.matches(&json!(0)));
+        assert!(!matcher.matches(&json!(1.0)));
+        assert!(matcher.matches(&json!(0.5)));
+    }
+
+    #[test]
+    fn test_unbounded() {
+        let matcher = RangeMatcher::new().min_inclusive(18.0);
+        assert!(matcher.matches(&json!(u64::MAX)));
+        assert!(!matcher.matches(&json!(-18)));
+        assert!(RangeMatcher::default().matches(&json!(-1e300)));
+    }
+
+    #[test]
+    fn test_non_numbers() {
+        let matcher = RangeMatcher::between(0.0, 100.0);
+        assert!(!matcher.matches(&json!("50")));
+        assert!(!matcher.matches(&json!(null)));
+        assert!(!matcher.matches(&json!([50])));
+    }
+
+    #[test]
+    fn test_description() {
+        assert_eq!(RangeMatcher::between(0.0, 1.5).description(), "is in range [0, 1.5]");
+        assert_eq!(RangeMatcher::new().min_exclusive(-1.0).description(), "is in range (-1, inf)");
+        assert_eq!(RangeMatcher::new().max_exclusive(100.0).description(), "is in range (-inf, 100)");
+    }
+}
@@ -1,6 +1,7 @@
 use super::JsonMatcher;
-use regex::Regex;
+use regex::{Regex, RegexBuilder};
 use serde_json::Value;
+use std::fmt;
 
 #[derive(Debug)]
 pub struct RegexMatcher {
@@ -28,6 +29,81 @@ impl JsonMatcher for RegexMatcher {
     }
 }
 
+/// Flags for compiling a regular expression, used by `matches_pattern_with_flags`.
+///
+/// All flags are off by default. Each method enables or disables one flag and
+/// returns the updated flags, so they can be combined in a chain.
+///
+/// # Examples
+///
+/// ```rust
+/// use json_test::RegexFlags;
+///
+/// let flags = RegexFlags::new()
+///     .case_insensitive(true)
+///     .multiline(true);
+/// assert_eq!(flags.to_string(), "case_insensitive, multiline");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RegexFlags {
+    case_insensitive: bool,
+    multiline: bool,
+    dot_matches_new_line: bool,
+}
+
+impl RegexFlags {
+    /// Creates flags with every flag disabled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Matches letters regardless of case, like `(?i)`.
+    pub fn case_insensitive(mut self, enabled: bool) -> Self {
+        self.case_insensitive = enabled;
+        self
+    }
+
+    /// Makes `^` and `$` match at the start and end of each line, like `(?m)`.
+    pub fn multiline(mut self, enabled: bool) -> Self {
+        self.multiline = enabled;
+        self
+    }
+
+    /// Makes `.` match `\n` as well, like `(?s)`.
+    pub fn dot_matches_new_line(mut self, enabled: bool) -> Self {
+        self.dot_matches_new_line = enabled;
+        self
+    }
+
+    pub(crate) fn build(&self, pattern: &str) -> Result<Regex, regex::Error> {
+        RegexBuilder::new(pattern)
+            .case_insensitive(self.case_insensitive)
+            .multi_line(self.multiline)
+            .dot_matches_new_line(self.dot_matches_new_line)
+            .build()
+    }
+}
+
+impl fmt::Display for RegexFlags {
+    /// Lists the enabled flags, or `none`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let enabled: Vec<&str> = [
+            (self.case_insensitive, "case_insensitive"),
+            (self.multiline, "multiline"),
+            (self.dot_matches_new_line, "dot_matches_new_line"),
+        ]
+        .into_iter()
+        .filter_map(|(on, name)| on.then_some(name))
+        .collect();
+
+        if enabled.is_empty() {
+            write!(f, "none")
+        } else {
+            write!(f, "{}", enabled.join(", "))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -68,4 +144,13 @@ mod tests {
         let matcher = RegexMatcher::new(r"\d+").unwrap();
         assert_eq!(matcher.description(), r#"matches regex pattern \d+"#);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_regex_flags() {
+        let flags = RegexFlags::new().case_insensitive(true).dot_matches_new_line(true);
+        assert!(flags.build("^abc.d$").unwrap().is_match("ABC\nD"));
+        assert!(!RegexFlags::new().build("^abc.d$").unwrap().is_match("ABC\nD"));
+        assert_eq!(flags.to_string(), "case_insensitive, dot_matches_new_line");
+        assert_eq!(RegexFlags::default().to_string(), "none");
+    }
+}
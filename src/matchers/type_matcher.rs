@@ -35,19 +35,32 @@ impl TypeMatcher {
     pub fn null() -> Self {
         Self::new("null")
     }
+
+    /// Returns the matcher for a JSON type name, or `None` for an unknown name.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "string" => Some(Self::string()),
+            "number" => Some(Self::number()),
+            "boolean" => Some(Self::boolean()),
+            "null" => Some(Self::null()),
+            "array" => Some(Self::array()),
+            "object" => Some(Self::object()),
+            _ => None,
+        }
+    }
 }
 
 impl JsonMatcher for TypeMatcher {
     fn matches(&self, value: &Value) -> bool {
-        match (self.expected_type, value) {
-            ("string", Value::String(_)) => true,
-            ("number", Value::Number(_)) => true,
-            ("boolean", Value::Bool(_)) => true,
-            ("null", Value::Null) => true,
-            ("array", Value::Array(_)) => true,
-            ("object", Value::Object(_)) => true,
-            _ => false,
-        }
+        matches!(
+            (self.expected_type, value),
+            ("string", Value::String(_))
+                | ("number", Value::Number(_))
+                | ("boolean", Value::Bool(_))
+                | ("null", Value::Null)
+                | ("array", Value::Array(_))
+                | ("object", Value::Object(_))
+        )
     }
 
     fn description(&self) -> String {